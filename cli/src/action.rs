@@ -47,6 +47,8 @@ pub enum Action {
         rpc_url: Option<String>,
         authority_path: Option<String>,
         approver_path: Option<String>,
+        escrow_id: Option<u64>,
+        escrow_authority: Option<String>,
     },
     EscrowConfig {
         config_account: Option<String>,
@@ -61,6 +63,18 @@ pub enum Action {
         mint: String,
         amount: f64,
     },
+    SetMintLimit {
+        mint: String,
+        amount_per_day: f64,
+        queue_threshold: f64,
+    },
+    Heartbeat,
+    ProposeAuthority {
+        new_authority: String,
+    },
+    AcceptAuthority,
+    Pause,
+    Unpause,
     Encrypt {
         private_key: String,
         password: String,
@@ -98,6 +112,8 @@ pub fn handler(action: Action) -> Result<()> {
         wss_url: config.wss_url,
         authority_path: config.authority_path,
         approver_path: config.approver_path,
+        escrow_id: config.escrow_id,
+        escrow_authority: config.escrow_authority,
     };
     let program = LockFundProgram::init(params);
     match action {
@@ -131,6 +147,11 @@ pub fn handler(action: Action) -> Result<()> {
             println_name_value("Authority Address: ", &authority_address);
             println_name_value("Authority Path: ", &config.authority_path);
             println_name_value("Approver Address: ", &approver_address);
+            println_name_value("Escrow Id: ", &config.escrow_id.to_string());
+            println_name_value(
+                "Escrow Authority: ",
+                &config.escrow_authority.clone().unwrap_or_default(),
+            );
             println_name_value(
                 "Escrow Config Address: ",
                 &program.config_account.to_string(),
@@ -141,6 +162,8 @@ pub fn handler(action: Action) -> Result<()> {
             rpc_url,
             authority_path,
             approver_path,
+            escrow_id,
+            escrow_authority,
         } => {
             let file_path = config_path!();
             if !Path::new(&file_path).exists() {
@@ -163,27 +186,80 @@ pub fn handler(action: Action) -> Result<()> {
                 config.approver_path = approver_path;
             }
 
+            if let Some(escrow_id) = escrow_id {
+                config.escrow_id = escrow_id;
+            }
+
+            if let Some(escrow_authority) = escrow_authority {
+                Pubkey::from_str(&escrow_authority)?;
+                config.escrow_authority = Some(escrow_authority);
+            }
+
             save_to_file(&config, file_path)?;
         }
         Action::EscrowConfig { config_account } => {
             let config_data: lock_fund::ConfigAccount = program.config_data(config_account)?;
             println_name_value("Authority: ", &config_data.authority.to_string());
-            println_name_value("Approver: ", &config_data.approver.to_string());
+            for approver in config_data.approvers() {
+                println_name_value("Approver: ", &approver.to_string());
+            }
+            println_name_value(
+                "Approver threshold: ",
+                &config_data.approver_threshold.to_string(),
+            );
             println_name_value("Recipient: ", &config_data.recipient.to_string());
             println_name_value(
                 "Enable transfer full: ",
                 &config_data.enable_transfer_full.to_string(),
             );
+            println_name_value("Paused: ", &config_data.paused.to_string());
+            for mint in config_data.allowed_mints() {
+                println_name_value("Allowed mint: ", &mint.to_string());
+            }
+            println_name_value(
+                "Total deposited lamports: ",
+                &config_data.total_deposited_lamports.to_string(),
+            );
+            println_name_value(
+                "Total withdrawn lamports: ",
+                &config_data.total_withdrawn_lamports.to_string(),
+            );
+            println_name_value(
+                "Withdrawal count: ",
+                &config_data.withdrawal_count.to_string(),
+            );
+            println_name_value(
+                "Last withdrawal timestamp: ",
+                &config_data.last_withdrawal_ts.to_string(),
+            );
+            println_name_value(
+                "Inactivity period: ",
+                &config_data.inactivity_period.to_string(),
+            );
+            println_name_value(
+                "Last activity timestamp: ",
+                &config_data.last_activity_ts.to_string(),
+            );
         }
 
         Action::InitEscrow { recipient } => {
             let params = CreateConfigParams {
+                escrow_id: program.escrow_id,
                 cliff_time_duration: 24 * 60 * 60,
                 amount_per_day: 1_000_000,
+                withdrawal_delay: 24 * 60 * 60,
                 update_actor_mode: 0,
                 enable_transfer_full: 0,
                 recipient: Pubkey::from_str(&recipient)?,
-                approver: program.approver.pubkey(),
+                approvers: vec![program.approver.pubkey()],
+                approver_threshold: 1,
+                vesting: None,
+                inactivity_period: 0,
+                refund_time_duration: 30 * 24 * 60 * 60,
+                destination_delay: 24 * 60 * 60,
+                queue_threshold: 0,
+                shares: vec![],
+                allowed_mints: vec![],
             };
             let sig = program.create_config(params).unwrap();
             println_name_value(
@@ -208,6 +284,50 @@ pub fn handler(action: Action) -> Result<()> {
             println_name_value("Success transfer Token: ", &bs58::encode(sig).into_string());
         }
 
+        Action::SetMintLimit {
+            mint,
+            amount_per_day,
+            queue_threshold,
+        } => {
+            let sig = program
+                .set_mint_limit(Pubkey::from_str(&mint)?, amount_per_day, queue_threshold)
+                .unwrap();
+            println_name_value("Success set mint limit: ", &bs58::encode(sig).into_string());
+        }
+
+        Action::Heartbeat => {
+            let sig = program.heartbeat().unwrap();
+            println_name_value("Success heartbeat: ", &bs58::encode(sig).into_string());
+        }
+
+        Action::ProposeAuthority { new_authority } => {
+            let sig = program
+                .propose_new_authority(Pubkey::from_str(&new_authority)?)
+                .unwrap();
+            println_name_value(
+                "Success propose new authority: ",
+                &bs58::encode(sig).into_string(),
+            );
+        }
+
+        Action::AcceptAuthority => {
+            let sig = program.accept_authority().unwrap();
+            println_name_value(
+                "Success accept authority: ",
+                &bs58::encode(sig).into_string(),
+            );
+        }
+
+        Action::Pause => {
+            let sig = program.pause().unwrap();
+            println_name_value("Success pause escrow: ", &bs58::encode(sig).into_string());
+        }
+
+        Action::Unpause => {
+            let sig = program.unpause().unwrap();
+            println_name_value("Success unpause escrow: ", &bs58::encode(sig).into_string());
+        }
+
         Action::Encrypt {
             private_key,
             password,
@@ -51,6 +51,19 @@ pub fn command_config() -> Command {
                         .long("approver_path")
                         .required(false)
                         .help("Approver for sign transaction"),
+                )
+                .arg(
+                    Arg::new("escrow_id")
+                        .short('e')
+                        .long("escrow_id")
+                        .required(false)
+                        .help("Id of the escrow to operate on"),
+                )
+                .arg(
+                    Arg::new("escrow_authority")
+                        .long("escrow_authority")
+                        .required(false)
+                        .help("Authority the escrow was created by, after a handover"),
                 ),
         )
 }
@@ -106,6 +119,49 @@ pub fn command_escrow() -> Command {
                         .help("Transfer Token from escrow"),
                 ),
         )
+        .subcommand(
+            Command::new("set_mint_limit")
+                .about("Set daily withdrawal limit of a mint")
+                .arg(
+                    Arg::new("mint")
+                        .short('m')
+                        .long("mint")
+                        .required(true)
+                        .help("Mint token the limit applies to"),
+                )
+                .arg(
+                    Arg::new("amount_per_day")
+                        .short('a')
+                        .long("amount_per_day")
+                        .required(true)
+                        .help("Max amount of token can be withdrawn per day"),
+                )
+                .arg(
+                    Arg::new("queue_threshold")
+                        .short('q')
+                        .long("queue_threshold")
+                        .required(true)
+                        .help("Max amount of token can be withdrawn without queueing, 0 disables"),
+                ),
+        )
+        .subcommand(Command::new("heartbeat").about("Prove authority is active, postpone recovery"))
+        .subcommand(
+            Command::new("propose_authority")
+                .about("Propose a new escrow authority, authority and approver sign")
+                .arg(
+                    Arg::new("new_authority")
+                        .short('n')
+                        .long("new_authority")
+                        .required(true)
+                        .help("Address of the new authority"),
+                ),
+        )
+        .subcommand(
+            Command::new("accept_authority")
+                .about("Accept the escrow authority, signed by the configured authority"),
+        )
+        .subcommand(Command::new("pause").about("Pause every transfer from escrow"))
+        .subcommand(Command::new("unpause").about("Unpause escrow, authority and approver sign"))
 }
 
 pub fn command_encrypt() -> Command {
@@ -10,6 +10,13 @@ pub struct ConfigFile {
     pub wss_url: String,
     pub approver_path: String,
     pub authority_path: String,
+    // Id of the escrow to operate on, one authority may own multiple escrows
+    #[serde(default)]
+    pub escrow_id: u64,
+    // Authority the escrow PDAs are derived from, the authority keypair when not set.
+    // Keeps the escrow reachable after an authority handover
+    #[serde(default)]
+    pub escrow_authority: Option<String>,
 }
 
 impl Default for ConfigFile {
@@ -19,6 +26,8 @@ impl Default for ConfigFile {
             wss_url: String::from(""),
             authority_path: String::from(""),
             approver_path: String::from(""),
+            escrow_id: 0,
+            escrow_authority: None,
         }
     }
 }
@@ -32,3 +41,19 @@ impl ConfigFile {
         crate::utils::save_to_file(self, file)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escrow_id_defaults_to_zero() {
+        let config: ConfigFile = serde_json::from_str(
+            r#"{"rpc_url":"","wss_url":"","approver_path":"","authority_path":""}"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.escrow_id, 0);
+        assert_eq!(config.escrow_authority, None);
+    }
+}
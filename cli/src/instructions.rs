@@ -9,17 +9,27 @@ use anchor_client::solana_sdk::{
     signer::{keypair::Keypair, Signer},
 };
 use anchor_client::Client;
-use anchor_spl::associated_token::{get_associated_token_address, spl_associated_token_account};
-use anchor_spl::token::{spl_token::state::Mint, ID};
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token::spl_token::state::Mint;
 use anyhow::{Ok, Result};
 
 pub struct CreateConfigParams {
+    pub escrow_id: u64,
     pub cliff_time_duration: u64,
     pub amount_per_day: u64,
+    pub withdrawal_delay: u64,
     pub update_actor_mode: u8,
     pub enable_transfer_full: u8,
     pub recipient: Pubkey,
-    pub approver: Pubkey,
+    pub approvers: Vec<Pubkey>,
+    pub approver_threshold: u8,
+    pub vesting: Option<lock_fund::VestingParams>,
+    pub inactivity_period: u64,
+    pub refund_time_duration: u64,
+    pub destination_delay: u64,
+    pub queue_threshold: u64,
+    pub shares: Vec<lock_fund::Share>,
+    pub allowed_mints: Vec<Pubkey>,
 }
 
 #[derive(Debug, Clone)]
@@ -28,18 +38,21 @@ pub struct InitProgramParams {
     pub wss_url: String,
     pub authority_path: String,
     pub approver_path: String,
+    pub escrow_id: u64,
+    pub escrow_authority: Option<String>,
 }
 
 pub struct LockFundProgram {
     pub program: anchor_client::Program<Rc<Keypair>>,
     pub approver: Keypair,
+    pub escrow_id: u64,
     pub escrow: Pubkey,
     pub config_account: Pubkey,
 }
 
 impl LockFundProgram {
     pub fn config_data(&self, config_account: Option<String>) -> Result<lock_fund::ConfigAccount> {
-        let mut account = self.escrow;
+        let mut account = self.config_account;
         if let Some(config_account) = config_account {
             account = Pubkey::from_str(&config_account)?;
         }
@@ -54,9 +67,14 @@ impl LockFundProgram {
             wss_url,
             approver_path,
             authority_path,
+            escrow_id,
+            escrow_authority,
         } = params;
         let authority = read_keypair_file(authority_path).unwrap();
-        let authority_pubkey = authority.pubkey();
+        // escrow PDAs keep the seeds of the authority that created them
+        let escrow_authority = escrow_authority
+            .map(|escrow_authority| Pubkey::from_str(&escrow_authority).unwrap())
+            .unwrap_or(authority.pubkey());
         let approver = read_keypair_file(approver_path).unwrap();
         let anchor_client = Client::new(
             anchor_client::Cluster::Custom(rpc_url, wss_url),
@@ -64,7 +82,11 @@ impl LockFundProgram {
         );
         let program = anchor_client.program(lock_fund::ID).unwrap();
         let (escrow, _bump) = Pubkey::find_program_address(
-            &[lock_fund::ESCROW_SEED, authority_pubkey.as_ref()],
+            &[
+                lock_fund::ESCROW_SEED,
+                escrow_authority.as_ref(),
+                escrow_id.to_le_bytes().as_ref(),
+            ],
             &lock_fund::ID,
         );
         let (config_account, _bump) = Pubkey::find_program_address(
@@ -74,6 +96,7 @@ impl LockFundProgram {
         LockFundProgram {
             program,
             approver,
+            escrow_id,
             escrow,
             config_account,
         }
@@ -87,15 +110,25 @@ impl LockFundProgram {
                 config_account: self.config_account,
                 escrow: self.escrow,
                 recipient: params.recipient,
-                approver: params.approver,
                 system_program: solana_program::system_program::id(),
             })
             .args(lock_fund::instruction::CreateConfig {
                 params: lock_fund::CreateConfigParams {
+                    escrow_id: params.escrow_id,
+                    approvers: params.approvers,
+                    approver_threshold: params.approver_threshold,
                     cliff_time_duration: params.cliff_time_duration,
                     amount_per_day: params.amount_per_day,
+                    withdrawal_delay: params.withdrawal_delay,
                     update_actor_mode: params.update_actor_mode,
                     enable_transfer_full: params.enable_transfer_full,
+                    vesting: params.vesting,
+                    inactivity_period: params.inactivity_period,
+                    refund_time_duration: params.refund_time_duration,
+                    destination_delay: params.destination_delay,
+                    queue_threshold: params.queue_threshold,
+                    shares: params.shares,
+                    allowed_mints: params.allowed_mints,
                 },
             })
             .send()?;
@@ -103,51 +136,166 @@ impl LockFundProgram {
         Ok(sig)
     }
 
+    pub fn mint_limit(&self, mint: &Pubkey) -> Pubkey {
+        let (mint_limit, _bump) = Pubkey::find_program_address(
+            &[
+                lock_fund::MINT_LIMIT_SEED,
+                self.config_account.as_ref(),
+                mint.as_ref(),
+            ],
+            &lock_fund::ID,
+        );
+        mint_limit
+    }
+
+    pub fn set_mint_limit(
+        &self,
+        mint: Pubkey,
+        amount_per_day: f64,
+        queue_threshold: f64,
+    ) -> Result<Signature> {
+        let mint_account = self.program.rpc().get_account(&mint)?;
+        let decimals = Mint::unpack(&mint_account.data[..Mint::LEN])?.decimals;
+        let raw_amount = amount_per_day * 10u64.pow(decimals as u32) as f64;
+        let raw_queue_threshold = queue_threshold * 10u64.pow(decimals as u32) as f64;
+
+        let sig = self
+            .program
+            .request()
+            .accounts(lock_fund::accounts::SetMintLimit {
+                config_account: self.config_account,
+                mint_limit: self.mint_limit(&mint),
+                mint_token: mint,
+                authority: self.program.payer(),
+                approver: self.approver.pubkey(),
+                system_program: solana_program::system_program::id(),
+            })
+            .args(lock_fund::instruction::SetMintLimit {
+                amount_per_day: raw_amount as u64,
+                queue_threshold: raw_queue_threshold as u64,
+            })
+            .signer(&self.approver)
+            .send()?;
+        Ok(sig)
+    }
+
+    pub fn heartbeat(&self) -> Result<Signature> {
+        let sig = self
+            .program
+            .request()
+            .accounts(lock_fund::accounts::Heartbeat {
+                config_account: self.config_account,
+                authority: self.program.payer(),
+            })
+            .args(lock_fund::instruction::Heartbeat {})
+            .send()?;
+        Ok(sig)
+    }
+
+    pub fn pause(&self) -> Result<Signature> {
+        let sig = self
+            .program
+            .request()
+            .accounts(lock_fund::accounts::Pause {
+                config_account: self.config_account,
+                actor: self.program.payer(),
+            })
+            .args(lock_fund::instruction::Pause {})
+            .send()?;
+        Ok(sig)
+    }
+
+    pub fn unpause(&self) -> Result<Signature> {
+        let sig = self
+            .program
+            .request()
+            .accounts(lock_fund::accounts::Unpause {
+                config_account: self.config_account,
+                authority: self.program.payer(),
+                approver: self.approver.pubkey(),
+            })
+            .args(lock_fund::instruction::Unpause {})
+            .signer(&self.approver)
+            .send()?;
+        Ok(sig)
+    }
+
+    pub fn propose_new_authority(&self, new_authority: Pubkey) -> Result<Signature> {
+        let sig = self
+            .program
+            .request()
+            .accounts(lock_fund::accounts::ProposeNewAuthority {
+                config_account: self.config_account,
+                authority: self.program.payer(),
+                approver: self.approver.pubkey(),
+            })
+            .args(lock_fund::instruction::ProposeNewAuthority { new_authority })
+            .signer(&self.approver)
+            .send()?;
+        Ok(sig)
+    }
+
+    pub fn accept_authority(&self) -> Result<Signature> {
+        // configured authority keypair is the pending authority
+        let sig = self
+            .program
+            .request()
+            .accounts(lock_fund::accounts::AcceptAuthority {
+                config_account: self.config_account,
+                new_authority: self.program.payer(),
+            })
+            .args(lock_fund::instruction::AcceptAuthority {})
+            .send()?;
+        Ok(sig)
+    }
+
     pub fn transfer_token(&self, mint: Pubkey, amount: f64) -> Result<Signature> {
         let config_account_data: lock_fund::ConfigAccount =
             self.program.account(self.config_account)?;
-        let escrow_token = get_associated_token_address(&self.escrow, &mint);
-        let recipient_token = get_associated_token_address(&config_account_data.recipient, &mint);
         let mint_account = self.program.rpc().get_account(&mint).unwrap();
-        let decimals = Mint::unpack(&mint_account.data).unwrap().decimals;
+        // mint owner is either the token or the token 2022 program
+        let token_program = mint_account.owner;
+        // token 2022 mints start with the same layout, extensions follow
+        let decimals = Mint::unpack(&mint_account.data[..Mint::LEN])
+            .unwrap()
+            .decimals;
         let raw_amount = amount * 10u64.pow(decimals as u32) as f64;
-
-        //
+        let escrow_token =
+            get_associated_token_address_with_program_id(&self.escrow, &mint, &token_program);
+        let recipient_token = get_associated_token_address_with_program_id(
+            &config_account_data.recipient,
+            &mint,
+            &token_program,
+        );
 
         let (event_authority, _bump) =
             Pubkey::find_program_address(&[b"__event_authority"], &lock_fund::ID);
 
-        // mut allow request_builders reassign again.
-        // to handle ownership & moved value
-        let mut request_builders = self.program.request();
-        let recipient_token_data = self.program.rpc().get_token_account(&recipient_token)?;
-        if recipient_token_data.is_none() {
-            let create_ata_ins =
-                spl_associated_token_account::instruction::create_associated_token_account(
-                    &self.program.payer(),
-                    &recipient_token,
-                    &mint,
-                    &ID,
-                );
-            // 
-            request_builders = request_builders.instruction(create_ata_ins);
-        }
-        let sig = request_builders
+        // recipient token account is created by the program when missing
+        let sig = self
+            .program
+            .request()
             .accounts(lock_fund::accounts::TransferToken {
                 config_account: self.config_account,
                 escrow: self.escrow,
                 escrow_token,
                 recipient_token,
                 recipient: config_account_data.recipient,
+                destination: None,
                 mint_token: mint,
+                mint_limit: self.mint_limit(&mint),
                 authority: self.program.payer(),
                 approver: self.approver.pubkey(),
-                token_program: ID,
+                token_program,
+                associated_token_program: anchor_spl::associated_token::ID,
+                system_program: solana_program::system_program::id(),
                 event_authority,
                 program: lock_fund::ID,
             })
             .args(lock_fund::instruction::TransferToken {
                 amount: raw_amount as u64,
+                // the configured approver signs as account, no other approvers
+                approver_count: 0,
             })
             .signer(&self.approver)
             .send()?;
@@ -167,6 +315,7 @@ impl LockFundProgram {
                 config_account: self.config_account,
                 escrow: self.escrow,
                 recipient: config_account_data.recipient,
+                destination: None,
                 authority: self.program.payer(),
                 approver: self.approver.pubkey(),
                 event_authority,
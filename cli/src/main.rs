@@ -27,6 +27,11 @@ pub fn get_action(matches: &clap::ArgMatches) -> Result<action::Action> {
                     rpc_url: matches.get_one::<String>("rpc_url").cloned(),
                     authority_path: matches.get_one::<String>("authority_path").cloned(),
                     approver_path: matches.get_one::<String>("approver_path").cloned(),
+                    escrow_id: matches
+                        .get_one::<String>("escrow_id")
+                        .map(|escrow_id| escrow_id.parse::<u64>())
+                        .transpose()?,
+                    escrow_authority: matches.get_one::<String>("escrow_authority").cloned(),
                 })
             }
             _ => unreachable!(),
@@ -61,6 +66,41 @@ pub fn get_action(matches: &clap::ArgMatches) -> Result<action::Action> {
                     amount: matches.get_one::<String>("amount").unwrap().parse::<f64>()?,
                 })
             }
+
+            Some("set_mint_limit") => {
+                let matches = sub_m("escrow")?.subcommand_matches("set_mint_limit").unwrap();
+                Ok(action::Action::SetMintLimit {
+                    mint: matches.get_one::<String>("mint").unwrap().to_string(),
+                    amount_per_day: matches
+                        .get_one::<String>("amount_per_day")
+                        .unwrap()
+                        .parse::<f64>()?,
+                    queue_threshold: matches
+                        .get_one::<String>("queue_threshold")
+                        .unwrap()
+                        .parse::<f64>()?,
+                })
+            }
+
+            Some("heartbeat") => Ok(action::Action::Heartbeat),
+
+            Some("propose_authority") => {
+                let matches = sub_m("escrow")?
+                    .subcommand_matches("propose_authority")
+                    .unwrap();
+                Ok(action::Action::ProposeAuthority {
+                    new_authority: matches
+                        .get_one::<String>("new_authority")
+                        .unwrap()
+                        .to_string(),
+                })
+            }
+
+            Some("accept_authority") => Ok(action::Action::AcceptAuthority),
+
+            Some("pause") => Ok(action::Action::Pause),
+
+            Some("unpause") => Ok(action::Action::Unpause),
             _ => unreachable!(),
         },
 
@@ -123,6 +163,18 @@ fn main() {
             action::handler(action).unwrap();
         }
 
+        action::Action::SetMintLimit { .. } => {
+            action::handler(action).unwrap();
+        }
+
+        action::Action::Heartbeat | action::Action::Pause | action::Action::Unpause => {
+            action::handler(action).unwrap();
+        }
+
+        action::Action::ProposeAuthority { .. } | action::Action::AcceptAuthority => {
+            action::handler(action).unwrap();
+        }
+
         action::Action::Encrypt { .. } => {
             action::handler(action).unwrap();
         }
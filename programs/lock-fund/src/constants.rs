@@ -4,4 +4,36 @@ use anchor_lang::prelude::*;
 pub const CONFIG_SEED: &[u8] = b"config_seed";
 #[constant]
 pub const ESCROW_SEED: &[u8] = b"escrow_seed";
+#[constant]
+pub const MINT_LIMIT_SEED: &[u8] = b"mint_limit_seed";
+#[constant]
+pub const PROPOSAL_SEED: &[u8] = b"proposal_seed";
+#[constant]
+pub const DESTINATION_SEED: &[u8] = b"destination_seed";
+
+// Length of the daily withdrawal window in seconds
+pub const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+// Granularity of the rolling daily withdrawal window in seconds
+pub const SECONDS_PER_HOUR: u64 = 60 * 60;
+
+// Number of hourly buckets in the rolling daily withdrawal window
+pub const DAILY_LIMIT_BUCKETS: usize = 24;
+
+// Max number of approvers of an escrow
+pub const MAX_APPROVERS: usize = 5;
+
+// Max number of mints in the mint allowlist of an escrow
+pub const MAX_ALLOWED_MINTS: usize = 5;
+
+// Max number of recipients sharing a distribution
+pub const MAX_SHARES: usize = 5;
+
+// Shares are expressed in basis points of this total
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+// Max cliff time duration of an escrow in seconds, 10 years
+pub const MAX_CLIFF_TIME_DURATION: u64 = 10 * 365 * SECONDS_PER_DAY;
 
+// Max refund time duration of an escrow in seconds, 10 years
+pub const MAX_REFUND_TIME_DURATION: u64 = 10 * 365 * SECONDS_PER_DAY;
@@ -10,5 +10,79 @@ pub enum LockFundEscrowError {
     #[msg("invalid escrow")]
     InvalidEscrow,
     #[msg("invalid Recipient")]
-    InvalidRecipient
+    InvalidRecipient,
+    #[msg("cliff time not reached")]
+    CliffTimeNotReached,
+    #[msg("amount exceeds daily withdrawal limit")]
+    DailyLimitExceeded,
+    #[msg("math overflow")]
+    MathOverflow,
+    #[msg("transfer full is disabled")]
+    TransferFullDisabled,
+    #[msg("invalid update actor mode")]
+    InvalidActorMode,
+    #[msg("actor is not updatable")]
+    ActorNotUpdatable,
+    #[msg("invalid approver set")]
+    InvalidApproverSet,
+    #[msg("invalid approver threshold")]
+    InvalidApproverThreshold,
+    #[msg("not enough approvers signed")]
+    InsufficientApprovals,
+    #[msg("invalid proposal expiry")]
+    InvalidExpiry,
+    #[msg("proposal expired")]
+    ProposalExpired,
+    #[msg("approver already approved")]
+    AlreadyApproved,
+    #[msg("invalid proposal mint")]
+    InvalidProposalMint,
+    #[msg("withdrawal is still timelocked")]
+    WithdrawalTimelocked,
+    #[msg("invalid vesting schedule")]
+    InvalidVestingSchedule,
+    #[msg("amount exceeds vested balance")]
+    VestingLimitExceeded,
+    #[msg("escrow is not empty")]
+    EscrowNotEmpty,
+    #[msg("amount per day must be greater than zero")]
+    InvalidAmountPerDay,
+    #[msg("enable transfer full must be 0 or 1")]
+    InvalidTransferFullFlag,
+    #[msg("cliff time duration is too long")]
+    CliffTimeTooLong,
+    #[msg("escrow is paused")]
+    EscrowPaused,
+    #[msg("signer is not the pending authority")]
+    InvalidPendingAuthority,
+    #[msg("recovery is disabled")]
+    RecoveryDisabled,
+    #[msg("authority is still active")]
+    AuthorityStillActive,
+    #[msg("refund time duration is too long")]
+    RefundTimeTooLong,
+    #[msg("refund is still timelocked")]
+    RefundTimelocked,
+    #[msg("destination is not active yet")]
+    DestinationNotActive,
+    #[msg("invalid share table")]
+    InvalidShareTable,
+    #[msg("distribution is disabled")]
+    DistributionDisabled,
+    #[msg("invalid mint allowlist")]
+    InvalidMintAllowlist,
+    #[msg("mint is not allowed")]
+    MintNotAllowed,
+    #[msg("unexpected account")]
+    UnexpectedAccount,
+    #[msg("proposals, mint limits or destinations of the config are still open")]
+    ConfigAccountsOpen,
+    #[msg("refund exceeds the balance not vested to the recipient")]
+    RefundExceedsUnvested,
+    #[msg("approver count exceeds the remaining accounts")]
+    InvalidApproverCount,
+    #[msg("withdrawal above the queue threshold must be queued")]
+    WithdrawalMustBeQueued,
+    #[msg("escrow token account of a mint limit is missing")]
+    EscrowTokenAccountMissing,
 }
\ No newline at end of file
@@ -1,20 +1,190 @@
 use anchor_lang::prelude::*;
 
+use crate::Share;
+
 #[event]
 pub struct CreateConfigEvent {
     pub authority: Pubkey,
-    pub approver: Pubkey,
+    pub approvers: Vec<Pubkey>,
+    pub approver_threshold: u8,
     pub recipient: Pubkey,
+    pub escrow: Pubkey,
+    pub escrow_id: u64,
     pub cliff_time_duration: u64,
     pub amount_per_day: u64,
+    pub withdrawal_delay: u64,
     pub update_actor_mode: u8,
     pub enable_transfer_full: u8,
+    pub vesting_mode: u8,
+    pub inactivity_period: u64,
+    pub refund_time_duration: u64,
+    pub destination_delay: u64,
+    pub queue_threshold: u64,
+    pub shares: Vec<Share>,
+    pub allowed_mints: Vec<Pubkey>,
+}
+
+#[event]
+pub struct ConfigUpdatedEvent {
+    pub config_account: Pubkey,
+    pub update_actor_mode: u8,
+    pub old_actor: Pubkey,
+    pub new_actor: Pubkey,
+}
+
+#[event]
+pub struct AuthorityProposedEvent {
+    pub config_account: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct ApproversUpdatedEvent {
+    pub config_account: Pubkey,
+    pub approvers: Vec<Pubkey>,
+    pub approver_threshold: u8,
+}
+
+#[event]
+pub struct AllowedMintsUpdatedEvent {
+    pub config_account: Pubkey,
+    pub allowed_mints: Vec<Pubkey>,
+}
+
+#[event]
+pub struct SetMintLimitEvent {
+    pub config_account: Pubkey,
+    pub mint: Pubkey,
+    pub amount_per_day: u64,
+    pub queue_threshold: u64,
+}
+
+#[event]
+pub struct WithdrawalProposedEvent {
+    pub config_account: Pubkey,
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub expiry: u64,
+}
+
+#[event]
+pub struct WithdrawalApprovedEvent {
+    pub config_account: Pubkey,
+    pub proposal: Pubkey,
+    pub approver: Pubkey,
+    pub approval_count: u8,
+}
+
+#[event]
+pub struct WithdrawalQueuedEvent {
+    pub config_account: Pubkey,
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub executable_at: u64,
+    pub expiry: u64,
+}
+
+#[event]
+pub struct WithdrawalVetoedEvent {
+    pub config_account: Pubkey,
+    pub proposal: Pubkey,
+    pub approver: Pubkey,
+}
+
+#[event]
+pub struct WithdrawalExecutedEvent {
+    pub config_account: Pubkey,
+    pub proposal: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct VestedBalanceEvent {
+    pub config_account: Pubkey,
+    pub mint: Pubkey,
+    pub vested_amount: u64,
+    pub withdrawn_amount: u64,
+}
+
+#[event]
+pub struct ConfigClosedEvent {
+    pub config_account: Pubkey,
+    pub authority: Pubkey,
+    pub escrow: Pubkey,
+}
+
+#[event]
+pub struct DepositEvent {
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub config_account: Pubkey,
+    // Token mint deposited, Pubkey::default() for SOL
+    pub mint: Pubkey,
+    // Amount sent by the depositor
+    pub amount: u64,
+    // Amount the escrow received after Token-2022 transfer fees
+    pub net_amount: u64,
+}
+
+#[event]
+pub struct DestinationAddedEvent {
+    pub config_account: Pubkey,
+    pub destination: Pubkey,
+    pub activated_at: u64,
+}
+
+#[event]
+pub struct DestinationRemovedEvent {
+    pub config_account: Pubkey,
+    pub destination: Pubkey,
+}
+
+#[event]
+pub struct HeartbeatEvent {
+    pub config_account: Pubkey,
+    pub authority: Pubkey,
+    pub last_activity_ts: u64,
+}
+
+#[event]
+pub struct EscrowPausedEvent {
+    pub config_account: Pubkey,
+    pub actor: Pubkey,
+}
+
+#[event]
+pub struct EscrowUnpausedEvent {
+    pub config_account: Pubkey,
+}
+
+#[event]
+pub struct RefundEvent {
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub config_account: Pubkey,
+    pub mint: Pubkey,
+    // Amount sent by the escrow
+    pub amount: u64,
+    // Amount received after Token-2022 transfer fees
+    pub net_amount: u64,
 }
 
 #[event]
 pub struct TransferEvent {
     pub from: Pubkey,
     pub to: Pubkey,
+    // Wallet owning the `to` token account, config recipient or an allowlisted destination.
+    // None on SOL transfers, `to` is already the wallet
+    pub recipient: Option<Pubkey>,
     pub config_account: Pubkey,
-    pub amount: u64
+    // Amount sent by the escrow
+    pub amount: u64,
+    // Amount received after Token-2022 transfer fees
+    pub net_amount: u64,
 }
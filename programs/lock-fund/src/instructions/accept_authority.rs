@@ -0,0 +1,24 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(mut)]
+    pub config_account: AccountLoader<'info, ConfigAccount>,
+
+    /// Pending authority.
+    pub new_authority: Signer<'info>,
+}
+
+pub fn accept_authority_handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let mut config_account = ctx.accounts.config_account.load_mut()?;
+    let old_actor = config_account.accept_authority(ctx.accounts.new_authority.key())?;
+
+    emit!(ConfigUpdatedEvent {
+        config_account: ctx.accounts.config_account.key(),
+        update_actor_mode: UpdateActorMode::Authority as u8,
+        old_actor,
+        new_actor: ctx.accounts.new_authority.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct AddDestination<'info> {
+    #[account(mut, has_one = authority)]
+    pub config_account: AccountLoader<'info, ConfigAccount>,
+
+    #[account(
+        init,
+        seeds = [
+            DESTINATION_SEED.as_ref(),
+            config_account.key().as_ref(),
+            destination.key().as_ref(),
+        ],
+        bump,
+        payer = authority,
+        space = 8 + Destination::INIT_SPACE
+    )]
+    pub destination_entry: Account<'info, Destination>,
+
+    /// CHECK: wallet allowed to receive funds.
+    pub destination: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Approver, other approvers sign as remaining accounts.
+    pub approver: Signer<'info>,
+    /// system program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Allowlist a destination, it can receive funds once `destination_delay` has passed.
+pub fn add_destination_handler(ctx: Context<AddDestination>) -> Result<()> {
+    let mut config_account = ctx.accounts.config_account.load_mut()?;
+    config_account.verify_approvals(&ctx.accounts.approver, ctx.remaining_accounts)?;
+    config_account.destination_count += 1;
+    let activated_at = (Clock::get()?.unix_timestamp as u64)
+        .checked_add(config_account.destination_delay)
+        .ok_or(LockFundEscrowError::MathOverflow)?;
+
+    let destination_entry = &mut ctx.accounts.destination_entry;
+    destination_entry.config_account = ctx.accounts.config_account.key();
+    destination_entry.destination = ctx.accounts.destination.key();
+    destination_entry.activated_at = activated_at;
+    destination_entry.bump = ctx.bumps.destination_entry;
+
+    emit!(DestinationAddedEvent {
+        config_account: ctx.accounts.config_account.key(),
+        destination: ctx.accounts.destination.key(),
+        activated_at,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ApproveWithdrawal<'info> {
+    pub config_account: AccountLoader<'info, ConfigAccount>,
+
+    #[account(mut, has_one = config_account)]
+    pub proposal: Account<'info, WithdrawalProposal>,
+
+    pub approver: Signer<'info>,
+}
+
+pub fn approve_withdrawal_handler(ctx: Context<ApproveWithdrawal>) -> Result<()> {
+    let config_account = ctx.accounts.config_account.load()?;
+    require!(
+        config_account.is_approver(ctx.accounts.approver.key),
+        LockFundEscrowError::Unauthorize
+    );
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.validate_not_expired(Clock::get()?.unix_timestamp as u64)?;
+    proposal.approve(&config_account, ctx.accounts.approver.key())?;
+
+    emit!(WithdrawalApprovedEvent {
+        config_account: ctx.accounts.config_account.key(),
+        proposal: proposal.key(),
+        approver: ctx.accounts.approver.key(),
+        approval_count: proposal.approval_count,
+    });
+
+    Ok(())
+}
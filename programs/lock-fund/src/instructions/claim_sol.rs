@@ -0,0 +1,59 @@
+use crate::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimSol<'info> {
+    #[account(mut, has_one = recipient)]
+    pub config_account: AccountLoader<'info, ConfigAccount>,
+
+    /// CHECK: account will transfer fund
+    #[account(mut, constraint = escrow.key() == config_account.load()?.escrow @ LockFundEscrowError::InvalidEscrow)]
+    pub escrow: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    /// system program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Recipient pulls SOL it is entitled to, bounded by the cliff, the daily limit
+/// and the vesting schedule. No approver signature is needed.
+pub fn claim_sol_handler(ctx: Context<ClaimSol>, amount: u64) -> Result<()> {
+    let mut config_account = ctx.accounts.config_account.load_mut()?;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    config_account.validate_not_paused()?;
+    config_account.validate_cliff_time(current_time)?;
+    config_account.consume_daily_limit(current_time, amount)?;
+    if let Some(vested_amount) =
+        config_account.consume_vesting(current_time, &Pubkey::default(), amount)?
+    {
+        emit_cpi!(VestedBalanceEvent {
+            config_account: ctx.accounts.config_account.key(),
+            mint: Pubkey::default(),
+            vested_amount,
+            withdrawn_amount: config_account.vesting_withdrawn,
+        });
+    }
+    config_account.record_withdrawal(current_time, amount)?;
+    let escrow_seeds = escrow_seeds!(config_account);
+
+    transfer_sol_from_escrow(
+        &ctx.accounts.system_program,
+        ctx.accounts.escrow.to_account_info(),
+        ctx.accounts.recipient.to_account_info(),
+        &[&escrow_seeds[..]],
+        amount,
+    )?;
+
+    emit_cpi!(TransferEvent {
+        from: ctx.accounts.escrow.key(),
+        to: ctx.accounts.recipient.key(),
+        recipient: None,
+        config_account: ctx.accounts.config_account.key(),
+        amount,
+        net_amount: amount,
+    });
+
+    Ok(())
+}
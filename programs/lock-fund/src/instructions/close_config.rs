@@ -0,0 +1,155 @@
+use anchor_spl::{
+    token::Token,
+    token_2022::Token2022,
+    token_interface::{close_account, CloseAccount, TokenAccount},
+};
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct CloseConfig<'info> {
+    #[account(mut, has_one = authority, close = authority)]
+    pub config_account: AccountLoader<'info, ConfigAccount>,
+
+    /// CHECK: escrow vault
+    #[account(mut, constraint = escrow.key() == config_account.load()?.escrow @ LockFundEscrowError::InvalidEscrow)]
+    pub escrow: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Approver, other approvers sign as remaining accounts.
+    pub approver: Signer<'info>,
+
+    /// system program.
+    pub system_program: Program<'info, System>,
+
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+
+    /// Token 2022 program.
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+/// Tear down an empty escrow. Escrow token accounts are passed as writable remaining
+/// accounts, each of them must be empty and is closed with its rent sent to the authority.
+/// Every withdrawal proposal, mint limit and destination of the config must be passed
+/// as well and is closed the same way, so none of them outlives the config. Each closed
+/// mint limit requires an escrow token account of its mint among them, so no mint with
+/// a limit can be left with a balance.
+pub fn close_config_handler<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, CloseConfig<'info>>,
+) -> Result<()> {
+    let config_account = ctx.accounts.config_account.load()?;
+    config_account.verify_approvals(&ctx.accounts.approver, ctx.remaining_accounts)?;
+    let escrow_seeds = escrow_seeds!(config_account);
+    let config_key = ctx.accounts.config_account.key();
+    let authority = ctx.accounts.authority.to_account_info();
+    let mut open_proposal_count = config_account.open_proposal_count;
+    let mut mint_limit_count = config_account.mint_limit_count;
+    let mut destination_count = config_account.destination_count;
+    let mut mint_limit_mints = Vec::new();
+    let mut escrow_token_mints = Vec::new();
+
+    for account in ctx
+        .remaining_accounts
+        .iter()
+        .filter(|account| !account.is_signer)
+    {
+        if account.owner == ctx.program_id {
+            if let Ok(proposal) = Account::<WithdrawalProposal>::try_from(account) {
+                require_keys_eq!(
+                    proposal.config_account,
+                    config_key,
+                    LockFundEscrowError::UnexpectedAccount
+                );
+                open_proposal_count = open_proposal_count
+                    .checked_sub(1)
+                    .ok_or(LockFundEscrowError::UnexpectedAccount)?;
+                proposal.close(authority.clone())?;
+            } else if let Ok(mint_limit) = Account::<MintLimit>::try_from(account) {
+                require_keys_eq!(
+                    mint_limit.config_account,
+                    config_key,
+                    LockFundEscrowError::UnexpectedAccount
+                );
+                mint_limit_count = mint_limit_count
+                    .checked_sub(1)
+                    .ok_or(LockFundEscrowError::UnexpectedAccount)?;
+                mint_limit_mints.push(mint_limit.mint);
+                mint_limit.close(authority.clone())?;
+            } else {
+                let destination = Account::<Destination>::try_from(account)?;
+                require_keys_eq!(
+                    destination.config_account,
+                    config_key,
+                    LockFundEscrowError::UnexpectedAccount
+                );
+                destination_count = destination_count
+                    .checked_sub(1)
+                    .ok_or(LockFundEscrowError::UnexpectedAccount)?;
+                destination.close(authority.clone())?;
+            }
+            continue;
+        }
+
+        let token_program = if account.owner == &ctx.accounts.token_program.key() {
+            ctx.accounts.token_program.to_account_info()
+        } else if account.owner == &ctx.accounts.token_2022_program.key() {
+            ctx.accounts.token_2022_program.to_account_info()
+        } else {
+            return err!(LockFundEscrowError::UnexpectedAccount);
+        };
+
+        let escrow_token = InterfaceAccount::<TokenAccount>::try_from(account)?;
+        require_keys_eq!(
+            escrow_token.owner,
+            ctx.accounts.escrow.key(),
+            LockFundEscrowError::InvalidEscrow
+        );
+        require!(
+            escrow_token.amount == 0,
+            LockFundEscrowError::EscrowNotEmpty
+        );
+        escrow_token_mints.push(escrow_token.mint);
+
+        close_account(CpiContext::new_with_signer(
+            token_program,
+            CloseAccount {
+                account: account.to_account_info(),
+                destination: ctx.accounts.authority.to_account_info(),
+                authority: ctx.accounts.escrow.to_account_info(),
+            },
+            &[&escrow_seeds[..]],
+        ))?;
+    }
+    require!(
+        open_proposal_count == 0 && mint_limit_count == 0 && destination_count == 0,
+        LockFundEscrowError::ConfigAccountsOpen
+    );
+    require!(
+        mint_limit_mints
+            .iter()
+            .all(|mint| escrow_token_mints.contains(mint)),
+        LockFundEscrowError::EscrowTokenAccountMissing
+    );
+
+    let rent = Rent::get()?.minimum_balance(0);
+    let escrow_lamports = ctx.accounts.escrow.lamports();
+    require!(escrow_lamports <= rent, LockFundEscrowError::EscrowNotEmpty);
+    transfer_sol_from_escrow(
+        &ctx.accounts.system_program,
+        ctx.accounts.escrow.to_account_info(),
+        ctx.accounts.authority.to_account_info(),
+        &[&escrow_seeds[..]],
+        escrow_lamports,
+    )?;
+
+    emit!(ConfigClosedEvent {
+        config_account: ctx.accounts.config_account.key(),
+        authority: ctx.accounts.authority.key(),
+        escrow: ctx.accounts.escrow.key(),
+    });
+
+    Ok(())
+}
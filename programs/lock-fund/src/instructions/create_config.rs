@@ -3,6 +3,7 @@ use anchor_lang::prelude::*;
 use crate::{ConfigAccount, CreateConfigEvent, CreateConfigParams, CONFIG_SEED, ESCROW_SEED};
 
 #[derive(Accounts)]
+#[instruction(params: CreateConfigParams)]
 pub struct CreateConfig<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -25,6 +26,7 @@ pub struct CreateConfig<'info> {
         seeds = [
             ESCROW_SEED.as_ref(),
             authority.key().as_ref(),
+            params.escrow_id.to_le_bytes().as_ref(),
         ],
         bump,
         payer = authority,
@@ -36,9 +38,6 @@ pub struct CreateConfig<'info> {
     /// CHECK: recipient account.
     pub recipient: UncheckedAccount<'info>,
 
-    /// CHECK: recipient account.
-    pub approver: UncheckedAccount<'info>,
-
     /// system program.
     pub system_program: Program<'info, System>,
 }
@@ -50,11 +49,12 @@ pub fn create_config_handler(
     params.init_config(
         &ctx.accounts.config_account,
         ctx.accounts.authority.key(),
-        ctx.accounts.approver.key(),
         ctx.accounts.recipient.key(),
         ctx.accounts.escrow.key(),
+        params.escrow_id,
         params.cliff_time_duration,
         params.amount_per_day,
+        params.withdrawal_delay,
         params.update_actor_mode,
         params.enable_transfer_full,
         ctx.bumps.config_account,
@@ -62,20 +62,42 @@ pub fn create_config_handler(
     )?;
 
     let &CreateConfigParams {
+        escrow_id,
+        ref approvers,
+        approver_threshold,
         cliff_time_duration,
         amount_per_day,
+        withdrawal_delay,
         update_actor_mode,
         enable_transfer_full,
+        ref vesting,
+        inactivity_period,
+        refund_time_duration,
+        destination_delay,
+        queue_threshold,
+        ref shares,
+        ref allowed_mints,
     } = params;
 
     emit!(CreateConfigEvent {
         authority: ctx.accounts.authority.key(),
-        approver: ctx.accounts.approver.key(),
+        approvers: approvers.clone(),
+        approver_threshold,
         recipient: ctx.accounts.recipient.key(),
+        escrow: ctx.accounts.escrow.key(),
+        escrow_id,
         cliff_time_duration,
         amount_per_day,
+        withdrawal_delay,
         update_actor_mode,
         enable_transfer_full,
+        vesting_mode: vesting.is_some() as u8,
+        inactivity_period,
+        refund_time_duration,
+        destination_delay,
+        queue_threshold,
+        shares: shares.clone(),
+        allowed_mints: allowed_mints.clone(),
     });
     Ok(())
 }
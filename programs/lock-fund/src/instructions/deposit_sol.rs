@@ -0,0 +1,48 @@
+use crate::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DepositSol<'info> {
+    #[account(mut)]
+    pub config_account: AccountLoader<'info, ConfigAccount>,
+
+    /// CHECK: account will receive fund
+    #[account(mut, constraint = escrow.key() == config_account.load()?.escrow @ LockFundEscrowError::InvalidEscrow)]
+    pub escrow: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    /// system program.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn deposit_sol_handler(ctx: Context<DepositSol>, amount: u64) -> Result<()> {
+    let mut config_account = ctx.accounts.config_account.load_mut()?;
+    config_account.total_deposited_lamports = config_account
+        .total_deposited_lamports
+        .checked_add(amount)
+        .ok_or(LockFundEscrowError::MathOverflow)?;
+
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.depositor.to_account_info(),
+                to: ctx.accounts.escrow.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    emit_cpi!(DepositEvent {
+        from: ctx.accounts.depositor.key(),
+        to: ctx.accounts.escrow.key(),
+        config_account: ctx.accounts.config_account.key(),
+        mint: Pubkey::default(),
+        amount,
+        net_amount: amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DepositToken<'info> {
+    #[account(mut)]
+    pub config_account: AccountLoader<'info, ConfigAccount>,
+
+    /// CHECK: This account use to validate escrow_token
+    #[account(constraint = escrow.key() == config_account.load()?.escrow @ LockFundEscrowError::InvalidEscrow)]
+    pub escrow: AccountInfo<'info>,
+
+    /// CHECK: Escrow Token Account.
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        associated_token::mint = mint_token,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub escrow_token: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Depositor Token Account.
+    #[account(
+        mut,
+        token::mint = mint_token,
+        token::authority = depositor
+    )]
+    pub depositor_token: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint_token: InterfaceAccount<'info, Mint>,
+
+    /// Deposit accounting of mint_token, created by the first deposit of the mint.
+    #[account(
+        init_if_needed,
+        seeds = [
+            MINT_LIMIT_SEED.as_ref(),
+            config_account.key().as_ref(),
+            mint_token.key().as_ref(),
+        ],
+        bump,
+        payer = depositor,
+        space = 8 + MintLimit::INIT_SPACE
+    )]
+    pub mint_limit: Account<'info, MintLimit>,
+
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    /// Token program.
+    pub token_program: Interface<'info, TokenInterface>,
+    /// Associated token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// system program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Deposit into the escrow token account. `total_deposited` records the amount the
+/// escrow received, net of Token-2022 transfer fees.
+pub fn deposit_token_handler(ctx: Context<DepositToken>, amount: u64) -> Result<()> {
+    let mut config_account = ctx.accounts.config_account.load_mut()?;
+    config_account.validate_mint(&ctx.accounts.mint_token.key())?;
+
+    let balance_before = ctx.accounts.escrow_token.amount;
+    transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.depositor_token.to_account_info(),
+                mint: ctx.accounts.mint_token.to_account_info(),
+                to: ctx.accounts.escrow_token.to_account_info(),
+                authority: ctx.accounts.depositor.to_account_info(),
+            },
+        ),
+        amount,
+        ctx.accounts.mint_token.decimals,
+    )?;
+    ctx.accounts.escrow_token.reload()?;
+    let deposited = ctx
+        .accounts
+        .escrow_token
+        .amount
+        .checked_sub(balance_before)
+        .ok_or(LockFundEscrowError::MathOverflow)?;
+
+    let mint_limit = &mut ctx.accounts.mint_limit;
+    if mint_limit.config_account == Pubkey::default() {
+        // withdrawals of the mint stay blocked until set_mint_limit sets amount_per_day
+        mint_limit.config_account = ctx.accounts.config_account.key();
+        mint_limit.mint = ctx.accounts.mint_token.key();
+        mint_limit.bump = ctx.bumps.mint_limit;
+        config_account.mint_limit_count += 1;
+    }
+    mint_limit.total_deposited = mint_limit
+        .total_deposited
+        .checked_add(deposited)
+        .ok_or(LockFundEscrowError::MathOverflow)?;
+
+    emit_cpi!(DepositEvent {
+        from: ctx.accounts.depositor_token.key(),
+        to: ctx.accounts.escrow_token.key(),
+        config_account: ctx.accounts.config_account.key(),
+        mint: ctx.accounts.mint_token.key(),
+        amount,
+        net_amount: deposited,
+    });
+
+    Ok(())
+}
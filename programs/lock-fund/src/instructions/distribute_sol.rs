@@ -0,0 +1,95 @@
+use crate::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DistributeSol<'info> {
+    #[account(mut, has_one = authority)]
+    pub config_account: AccountLoader<'info, ConfigAccount>,
+
+    /// CHECK: account will transfer fund
+    #[account(mut, constraint = escrow.key() == config_account.load()?.escrow @ LockFundEscrowError::InvalidEscrow)]
+    pub escrow: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Approver, other approvers sign as remaining accounts.
+    pub approver: Signer<'info>,
+    /// system program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Split `amount` across the share table. Share recipients are the last writable
+/// remaining accounts in share table order, after any other approver signers.
+/// Rounding dust stays in the escrow.
+pub fn distribute_sol_handler<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, DistributeSol<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let mut config_account = ctx.accounts.config_account.load_mut()?;
+    config_account.verify_approvals(&ctx.accounts.approver, ctx.remaining_accounts)?;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    config_account.validate_not_paused()?;
+    config_account.validate_cliff_time(current_time)?;
+
+    let legs = config_account.share_amounts(amount)?;
+    let recipients = share_recipient_accounts(ctx.remaining_accounts, legs.len())?;
+    let total: u64 = legs.iter().map(|(_, leg)| leg).sum();
+    config_account.validate_queue_threshold(total)?;
+    config_account.consume_daily_limit(current_time, total)?;
+    if let Some(vested_amount) =
+        config_account.consume_vesting(current_time, &Pubkey::default(), total)?
+    {
+        emit_cpi!(VestedBalanceEvent {
+            config_account: ctx.accounts.config_account.key(),
+            mint: Pubkey::default(),
+            vested_amount,
+            withdrawn_amount: config_account.vesting_withdrawn,
+        });
+    }
+    config_account.record_withdrawal(current_time, total)?;
+    let escrow_seeds = escrow_seeds!(config_account);
+
+    for (account, (recipient, leg)) in recipients.iter().zip(legs) {
+        require_keys_eq!(
+            *account.key,
+            recipient,
+            LockFundEscrowError::InvalidRecipient
+        );
+        if leg == 0 {
+            continue;
+        }
+
+        transfer_sol_from_escrow(
+            &ctx.accounts.system_program,
+            ctx.accounts.escrow.to_account_info(),
+            account.to_account_info(),
+            &[&escrow_seeds[..]],
+            leg,
+        )?;
+
+        emit_cpi!(TransferEvent {
+            from: ctx.accounts.escrow.key(),
+            to: account.key(),
+            recipient: None,
+            config_account: ctx.accounts.config_account.key(),
+            amount: leg,
+            net_amount: leg,
+        });
+    }
+
+    Ok(())
+}
+
+/// The last `share_count` remaining accounts receive the distribution legs.
+pub fn share_recipient_accounts<'a, 'info>(
+    remaining_accounts: &'a [AccountInfo<'info>],
+    share_count: usize,
+) -> Result<&'a [AccountInfo<'info>]> {
+    let start = remaining_accounts
+        .len()
+        .checked_sub(share_count)
+        .ok_or(LockFundEscrowError::InvalidRecipient)?;
+
+    Ok(&remaining_accounts[start..])
+}
@@ -0,0 +1,121 @@
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DistributeToken<'info> {
+    #[account(mut, has_one = authority)]
+    pub config_account: AccountLoader<'info, ConfigAccount>,
+
+    /// CHECK: This account use to validate escrow_token
+    #[account(constraint = escrow.key() == config_account.load()?.escrow @ LockFundEscrowError::InvalidEscrow)]
+    pub escrow: AccountInfo<'info>,
+
+    /// CHECK: Escrow Token Account.
+    #[account(
+        mut,
+        associated_token::mint = mint_token,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub escrow_token: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint_token: InterfaceAccount<'info, Mint>,
+
+    /// Daily withdrawal limit of mint_token.
+    #[account(
+        mut,
+        seeds = [
+            MINT_LIMIT_SEED.as_ref(),
+            config_account.key().as_ref(),
+            mint_token.key().as_ref(),
+        ],
+        bump = mint_limit.bump
+    )]
+    pub mint_limit: Account<'info, MintLimit>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Approver, other approvers sign as the first `approver_count` remaining accounts.
+    pub approver: Signer<'info>,
+    /// Token program.
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Split `amount` across the share table. Remaining accounts are `approver_count` other
+/// approver signers, then the transfer hook accounts, then the token accounts of the
+/// share recipients in share table order. Rounding dust stays in the escrow.
+pub fn distribute_token_handler<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, DistributeToken<'info>>,
+    amount: u64,
+    approver_count: u8,
+) -> Result<()> {
+    let mut config_account = ctx.accounts.config_account.load_mut()?;
+    let (approver_accounts, other_accounts) =
+        split_approver_accounts(ctx.remaining_accounts, approver_count)?;
+    config_account.verify_approvals(&ctx.accounts.approver, approver_accounts)?;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    config_account.validate_not_paused()?;
+    config_account.validate_mint(&ctx.accounts.mint_token.key())?;
+    config_account.validate_cliff_time(current_time)?;
+
+    let mint = ctx.accounts.mint_token.key();
+    let legs = config_account.share_amounts(amount)?;
+    let recipients = share_recipient_accounts(other_accounts, legs.len())?;
+    let hook_accounts = &other_accounts[..other_accounts.len() - recipients.len()];
+    let total: u64 = legs.iter().map(|(_, leg)| leg).sum();
+    ctx.accounts.mint_limit.validate_queue_threshold(total)?;
+    ctx.accounts
+        .mint_limit
+        .consume_daily_limit(current_time, total)?;
+    if let Some(vested_amount) = config_account.consume_vesting(current_time, &mint, total)? {
+        emit_cpi!(VestedBalanceEvent {
+            config_account: ctx.accounts.config_account.key(),
+            mint,
+            vested_amount,
+            withdrawn_amount: config_account.vesting_withdrawn,
+        });
+    }
+    config_account.record_withdrawal(current_time, 0)?;
+    ctx.accounts.mint_limit.record_withdrawal(total)?;
+    let escrow_seeds = escrow_seeds!(config_account);
+
+    for (account, (recipient, leg)) in recipients.iter().zip(legs) {
+        let recipient_token = InterfaceAccount::<TokenAccount>::try_from(account)?;
+        require!(
+            recipient_token.owner == recipient && recipient_token.mint == mint,
+            LockFundEscrowError::InvalidRecipient
+        );
+        if leg == 0 {
+            continue;
+        }
+
+        let net_amount = transfer_checked_from_escrow(
+            &ctx.accounts.token_program,
+            TransferChecked {
+                from: ctx.accounts.escrow_token.to_account_info(),
+                mint: ctx.accounts.mint_token.to_account_info(),
+                to: account.to_account_info(),
+                authority: ctx.accounts.escrow.to_account_info(),
+            },
+            hook_accounts,
+            &[&escrow_seeds[..]],
+            leg,
+            ctx.accounts.mint_token.decimals,
+        )?;
+
+        emit_cpi!(TransferEvent {
+            from: ctx.accounts.escrow_token.key(),
+            to: account.key(),
+            recipient: Some(recipient),
+            config_account: ctx.accounts.config_account.key(),
+            amount: leg,
+            net_amount,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use crate::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteWithdrawalSol<'info> {
+    #[account(mut, has_one = authority)]
+    pub config_account: AccountLoader<'info, ConfigAccount>,
+
+    #[account(
+        mut,
+        close = authority,
+        has_one = config_account,
+        constraint = proposal.mint == Pubkey::default() @ LockFundEscrowError::InvalidProposalMint
+    )]
+    pub proposal: Account<'info, WithdrawalProposal>,
+
+    /// CHECK: account will transfer fund
+    #[account(mut, constraint = escrow.key() == config_account.load()?.escrow @ LockFundEscrowError::InvalidEscrow)]
+    pub escrow: AccountInfo<'info>,
+
+    /// CHECK: account will receive fund
+    #[account(mut, constraint = recipient.key() == config_account.load()?.recipient @ LockFundEscrowError::InvalidRecipient)]
+    pub recipient: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// system program.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn execute_withdrawal_sol_handler(ctx: Context<ExecuteWithdrawalSol>) -> Result<()> {
+    let mut config_account = ctx.accounts.config_account.load_mut()?;
+    let proposal = &ctx.accounts.proposal;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    proposal.validate_executable(current_time)?;
+    proposal.verify_approvals(&config_account)?;
+    config_account.validate_not_paused()?;
+    config_account.validate_cliff_time(current_time)?;
+    config_account.consume_daily_limit(current_time, proposal.amount)?;
+    if let Some(vested_amount) =
+        config_account.consume_vesting(current_time, &Pubkey::default(), proposal.amount)?
+    {
+        emit_cpi!(VestedBalanceEvent {
+            config_account: ctx.accounts.config_account.key(),
+            mint: Pubkey::default(),
+            vested_amount,
+            withdrawn_amount: config_account.vesting_withdrawn,
+        });
+    }
+    config_account.record_withdrawal(current_time, proposal.amount)?;
+    config_account.open_proposal_count -= 1;
+    let escrow_seeds = escrow_seeds!(config_account);
+
+    transfer_sol_from_escrow(
+        &ctx.accounts.system_program,
+        ctx.accounts.escrow.to_account_info(),
+        ctx.accounts.recipient.to_account_info(),
+        &[&escrow_seeds[..]],
+        proposal.amount,
+    )?;
+
+    emit_cpi!(TransferEvent {
+        from: ctx.accounts.escrow.key(),
+        to: ctx.accounts.recipient.key(),
+        recipient: None,
+        config_account: ctx.accounts.config_account.key(),
+        amount: proposal.amount,
+        net_amount: proposal.amount,
+    });
+    emit_cpi!(WithdrawalExecutedEvent {
+        config_account: ctx.accounts.config_account.key(),
+        proposal: proposal.key(),
+        mint: proposal.mint,
+        amount: proposal.amount,
+    });
+
+    Ok(())
+}
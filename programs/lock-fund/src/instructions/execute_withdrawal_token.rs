@@ -0,0 +1,130 @@
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteWithdrawalToken<'info> {
+    #[account(mut, has_one = authority)]
+    pub config_account: AccountLoader<'info, ConfigAccount>,
+
+    #[account(
+        mut,
+        close = authority,
+        has_one = config_account,
+        constraint = proposal.mint == mint_token.key() @ LockFundEscrowError::InvalidProposalMint
+    )]
+    pub proposal: Account<'info, WithdrawalProposal>,
+
+    /// CHECK: This account use to validate escrow_token
+    #[account(constraint = escrow.key() == config_account.load()?.escrow @ LockFundEscrowError::InvalidEscrow)]
+    pub escrow: AccountInfo<'info>,
+
+    /// CHECK: Escrow Token Account.
+    #[account(
+        mut,
+        associated_token::mint = mint_token,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub escrow_token: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Recipient Token Account.
+    #[account(
+        mut,
+        associated_token::mint = mint_token,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program
+    )]
+    pub recipient_token: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: This account use to validate transfer recipient
+    #[account(constraint = recipient.key() == config_account.load()?.recipient @ LockFundEscrowError::InvalidRecipient)]
+    pub recipient: AccountInfo<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint_token: InterfaceAccount<'info, Mint>,
+
+    /// Daily withdrawal limit of mint_token.
+    #[account(
+        mut,
+        seeds = [
+            MINT_LIMIT_SEED.as_ref(),
+            config_account.key().as_ref(),
+            mint_token.key().as_ref(),
+        ],
+        bump = mint_limit.bump
+    )]
+    pub mint_limit: Account<'info, MintLimit>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Token program.
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Approvals are stored in the proposal, remaining accounts are the transfer hook
+/// accounts of mint_token.
+pub fn execute_withdrawal_token_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteWithdrawalToken<'info>>,
+) -> Result<()> {
+    let mut config_account = ctx.accounts.config_account.load_mut()?;
+    let proposal = &ctx.accounts.proposal;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    proposal.validate_executable(current_time)?;
+    proposal.verify_approvals(&config_account)?;
+    config_account.validate_not_paused()?;
+    config_account.validate_mint(&ctx.accounts.mint_token.key())?;
+    config_account.validate_cliff_time(current_time)?;
+    ctx.accounts
+        .mint_limit
+        .consume_daily_limit(current_time, proposal.amount)?;
+    if let Some(vested_amount) = config_account.consume_vesting(
+        current_time,
+        &ctx.accounts.mint_token.key(),
+        proposal.amount,
+    )? {
+        emit_cpi!(VestedBalanceEvent {
+            config_account: ctx.accounts.config_account.key(),
+            mint: ctx.accounts.mint_token.key(),
+            vested_amount,
+            withdrawn_amount: config_account.vesting_withdrawn,
+        });
+    }
+    config_account.record_withdrawal(current_time, 0)?;
+    config_account.open_proposal_count -= 1;
+    ctx.accounts.mint_limit.record_withdrawal(proposal.amount)?;
+    let escrow_seeds = escrow_seeds!(config_account);
+
+    let net_amount = transfer_checked_from_escrow(
+        &ctx.accounts.token_program,
+        TransferChecked {
+            from: ctx.accounts.escrow_token.to_account_info(),
+            mint: ctx.accounts.mint_token.to_account_info(),
+            to: ctx.accounts.recipient_token.to_account_info(),
+            authority: ctx.accounts.escrow.to_account_info(),
+        },
+        ctx.remaining_accounts,
+        &[&escrow_seeds[..]],
+        proposal.amount,
+        ctx.accounts.mint_token.decimals,
+    )?;
+
+    emit_cpi!(TransferEvent {
+        from: ctx.accounts.escrow_token.key(),
+        to: ctx.accounts.recipient_token.key(),
+        recipient: Some(ctx.accounts.recipient.key()),
+        config_account: ctx.accounts.config_account.key(),
+        amount: proposal.amount,
+        net_amount,
+    });
+    emit_cpi!(WithdrawalExecutedEvent {
+        config_account: ctx.accounts.config_account.key(),
+        proposal: proposal.key(),
+        mint: proposal.mint,
+        amount: proposal.amount,
+    });
+
+    Ok(())
+}
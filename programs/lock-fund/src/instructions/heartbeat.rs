@@ -0,0 +1,24 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct Heartbeat<'info> {
+    #[account(mut, has_one = authority)]
+    pub config_account: AccountLoader<'info, ConfigAccount>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Authority proves it is alive, postponing recipient recovery by `inactivity_period`.
+pub fn heartbeat_handler(ctx: Context<Heartbeat>) -> Result<()> {
+    let mut config_account = ctx.accounts.config_account.load_mut()?;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    config_account.last_activity_ts = current_time;
+
+    emit!(HeartbeatEvent {
+        config_account: ctx.accounts.config_account.key(),
+        authority: ctx.accounts.authority.key(),
+        last_activity_ts: current_time,
+    });
+
+    Ok(())
+}
@@ -1,7 +1,67 @@
+pub mod accept_authority;
+pub mod add_destination;
+pub mod approve_withdrawal;
+pub mod claim_sol;
+pub mod claim_token;
+pub mod close_config;
 pub mod create_config;
+pub mod deposit_sol;
+pub mod deposit_token;
+pub mod distribute_sol;
+pub mod distribute_token;
+pub mod execute_withdrawal_sol;
+pub mod execute_withdrawal_token;
+pub mod heartbeat;
+pub mod pause;
+pub mod propose_new_authority;
+pub mod propose_withdrawal;
+pub mod queue_withdrawal;
+pub mod recover_funds_sol;
+pub mod recover_funds_token;
+pub mod refund_sol;
+pub mod refund_token;
+pub mod remove_destination;
+pub mod set_allowed_mints;
+pub mod set_approvers;
+pub mod set_mint_limit;
+pub mod transfer_full_sol;
+pub mod transfer_full_token;
 pub mod transfer_token;
 pub mod transfer_sol;
+pub mod unpause;
+pub mod update_actor;
+pub mod veto_withdrawal;
 
+pub use accept_authority::*;
+pub use add_destination::*;
+pub use approve_withdrawal::*;
+pub use claim_sol::*;
+pub use claim_token::*;
+pub use close_config::*;
 pub use create_config::*;
+pub use deposit_sol::*;
+pub use deposit_token::*;
+pub use distribute_sol::*;
+pub use distribute_token::*;
+pub use execute_withdrawal_sol::*;
+pub use execute_withdrawal_token::*;
+pub use heartbeat::*;
+pub use pause::*;
+pub use propose_new_authority::*;
+pub use propose_withdrawal::*;
+pub use queue_withdrawal::*;
+pub use recover_funds_sol::*;
+pub use recover_funds_token::*;
+pub use refund_sol::*;
+pub use refund_token::*;
+pub use remove_destination::*;
+pub use set_allowed_mints::*;
+pub use set_approvers::*;
+pub use set_mint_limit::*;
+pub use transfer_full_sol::*;
+pub use transfer_full_token::*;
 pub use transfer_token::*;
-pub use transfer_sol::*;
\ No newline at end of file
+pub use transfer_sol::*;
+pub use unpause::*;
+pub use update_actor::*;
+pub use veto_withdrawal::*;
@@ -0,0 +1,28 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    #[account(mut)]
+    pub config_account: AccountLoader<'info, ConfigAccount>,
+
+    /// Authority or any approver.
+    pub actor: Signer<'info>,
+}
+
+/// Freeze every transfer out of the escrow except inactivity recovery, any single
+/// actor can pause.
+pub fn pause_handler(ctx: Context<Pause>) -> Result<()> {
+    let mut config_account = ctx.accounts.config_account.load_mut()?;
+    require!(
+        config_account.is_actor(ctx.accounts.actor.key),
+        LockFundEscrowError::Unauthorize
+    );
+    config_account.paused = 1;
+
+    emit!(EscrowPausedEvent {
+        config_account: ctx.accounts.config_account.key(),
+        actor: ctx.accounts.actor.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ProposeNewAuthority<'info> {
+    #[account(mut, has_one = authority)]
+    pub config_account: AccountLoader<'info, ConfigAccount>,
+
+    pub authority: Signer<'info>,
+
+    /// Approver, other approvers sign as remaining accounts.
+    pub approver: Signer<'info>,
+}
+
+/// First step of an authority handover, the new authority must accept it.
+pub fn propose_new_authority_handler(
+    ctx: Context<ProposeNewAuthority>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    let mut config_account = ctx.accounts.config_account.load_mut()?;
+    config_account.verify_approvals(&ctx.accounts.approver, ctx.remaining_accounts)?;
+    config_account.propose_authority(new_authority)?;
+
+    emit!(AuthorityProposedEvent {
+        config_account: ctx.accounts.config_account.key(),
+        authority: ctx.accounts.authority.key(),
+        pending_authority: new_authority,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ProposeWithdrawal<'info> {
+    #[account(mut, has_one = authority)]
+    pub config_account: AccountLoader<'info, ConfigAccount>,
+
+    #[account(
+        init,
+        seeds = [
+            PROPOSAL_SEED.as_ref(),
+            config_account.key().as_ref(),
+            config_account.load()?.proposal_count.to_le_bytes().as_ref(),
+        ],
+        bump,
+        payer = authority,
+        space = 8 + WithdrawalProposal::INIT_SPACE
+    )]
+    pub proposal: Account<'info, WithdrawalProposal>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// system program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Create a withdrawal proposal approvers can sign in separate transactions.
+/// `mint` is Pubkey::default() for SOL. Like a queued withdrawal, the proposal can
+/// only be executed after `withdrawal_delay` and any approver can veto it meanwhile.
+pub fn propose_withdrawal_handler(
+    ctx: Context<ProposeWithdrawal>,
+    mint: Pubkey,
+    amount: u64,
+    expiry: u64,
+) -> Result<()> {
+    let mut config_account = ctx.accounts.config_account.load_mut()?;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    let executable_at = current_time
+        .checked_add(config_account.withdrawal_delay)
+        .ok_or(LockFundEscrowError::MathOverflow)?;
+    require!(expiry > executable_at, LockFundEscrowError::InvalidExpiry);
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.config_account = ctx.accounts.config_account.key();
+    proposal.proposal_id = config_account.proposal_count;
+    proposal.mint = mint;
+    proposal.amount = amount;
+    proposal.executable_at = executable_at;
+    proposal.expiry = expiry;
+    proposal.bump = ctx.bumps.proposal;
+
+    config_account.proposal_count += 1;
+    config_account.open_proposal_count += 1;
+
+    emit!(WithdrawalProposedEvent {
+        config_account: ctx.accounts.config_account.key(),
+        proposal: proposal.key(),
+        proposal_id: proposal.proposal_id,
+        mint,
+        amount,
+        expiry,
+    });
+
+    Ok(())
+}
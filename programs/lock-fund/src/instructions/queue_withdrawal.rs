@@ -0,0 +1,85 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct QueueWithdrawal<'info> {
+    #[account(mut, has_one = authority)]
+    pub config_account: AccountLoader<'info, ConfigAccount>,
+
+    #[account(
+        init,
+        seeds = [
+            PROPOSAL_SEED.as_ref(),
+            config_account.key().as_ref(),
+            config_account.load()?.proposal_count.to_le_bytes().as_ref(),
+        ],
+        bump,
+        payer = authority,
+        space = 8 + WithdrawalProposal::INIT_SPACE
+    )]
+    pub proposal: Account<'info, WithdrawalProposal>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Approver, other approvers sign as remaining accounts.
+    pub approver: Signer<'info>,
+
+    /// system program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Queue a withdrawal signed by authority and approvers. It can be executed once
+/// `withdrawal_delay` has passed, any approver can veto it meanwhile. The daily
+/// limit still applies on execution. `mint` is Pubkey::default() for SOL.
+pub fn queue_withdrawal_handler(
+    ctx: Context<QueueWithdrawal>,
+    mint: Pubkey,
+    amount: u64,
+    expiry: u64,
+) -> Result<()> {
+    let mut config_account = ctx.accounts.config_account.load_mut()?;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    let executable_at = current_time
+        .checked_add(config_account.withdrawal_delay)
+        .ok_or(LockFundEscrowError::MathOverflow)?;
+    require!(expiry > executable_at, LockFundEscrowError::InvalidExpiry);
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.config_account = ctx.accounts.config_account.key();
+    proposal.proposal_id = config_account.proposal_count;
+    proposal.mint = mint;
+    proposal.amount = amount;
+    proposal.executable_at = executable_at;
+    proposal.expiry = expiry;
+    proposal.queued = 1;
+    proposal.bump = ctx.bumps.proposal;
+
+    let approvers = std::iter::once(ctx.accounts.approver.key).chain(
+        ctx.remaining_accounts
+            .iter()
+            .filter(|account| account.is_signer)
+            .map(|account| account.key),
+    );
+    for approver in approvers {
+        // the approver signer may be passed again as a remaining account
+        if config_account.is_approver(approver) && !proposal.has_approved(approver) {
+            proposal.approve(&config_account, *approver)?;
+        }
+    }
+    proposal.verify_approvals(&config_account)?;
+
+    config_account.proposal_count += 1;
+    config_account.open_proposal_count += 1;
+
+    emit!(WithdrawalQueuedEvent {
+        config_account: ctx.accounts.config_account.key(),
+        proposal: proposal.key(),
+        proposal_id: proposal.proposal_id,
+        mint,
+        amount,
+        executable_at,
+        expiry,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use crate::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RecoverFundsSol<'info> {
+    #[account(mut, has_one = recipient)]
+    pub config_account: AccountLoader<'info, ConfigAccount>,
+
+    /// CHECK: account will transfer fund
+    #[account(mut, constraint = escrow.key() == config_account.load()?.escrow @ LockFundEscrowError::InvalidEscrow)]
+    pub escrow: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    /// system program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Recipient sweeps every lamport above the rent-exempt reserve once the authority
+/// missed heartbeats for `inactivity_period`. Neither approvers nor the daily limit
+/// are involved, the cliff and the vested balance still apply. Pausing does not
+/// block recovery, an inactive authority could otherwise lock the funds forever.
+pub fn recover_funds_sol_handler(ctx: Context<RecoverFundsSol>) -> Result<()> {
+    let mut config_account = ctx.accounts.config_account.load_mut()?;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    config_account.validate_recoverable(current_time)?;
+    config_account.validate_cliff_time(current_time)?;
+
+    let mut amount = ctx
+        .accounts
+        .escrow
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(0));
+    if let Some(available) =
+        config_account.available_vested_amount(current_time, &Pubkey::default())
+    {
+        amount = amount.min(available);
+    }
+    if let Some(vested_amount) =
+        config_account.consume_vesting(current_time, &Pubkey::default(), amount)?
+    {
+        emit_cpi!(VestedBalanceEvent {
+            config_account: ctx.accounts.config_account.key(),
+            mint: Pubkey::default(),
+            vested_amount,
+            withdrawn_amount: config_account.vesting_withdrawn,
+        });
+    }
+    config_account.record_withdrawal(current_time, amount)?;
+    let escrow_seeds = escrow_seeds!(config_account);
+
+    transfer_sol_from_escrow(
+        &ctx.accounts.system_program,
+        ctx.accounts.escrow.to_account_info(),
+        ctx.accounts.recipient.to_account_info(),
+        &[&escrow_seeds[..]],
+        amount,
+    )?;
+
+    emit_cpi!(TransferEvent {
+        from: ctx.accounts.escrow.key(),
+        to: ctx.accounts.recipient.key(),
+        recipient: None,
+        config_account: ctx.accounts.config_account.key(),
+        amount,
+        net_amount: amount,
+    });
+
+    Ok(())
+}
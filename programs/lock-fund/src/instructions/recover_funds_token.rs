@@ -0,0 +1,109 @@
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RecoverFundsToken<'info> {
+    #[account(mut, has_one = recipient)]
+    pub config_account: AccountLoader<'info, ConfigAccount>,
+
+    /// CHECK: This account use to validate escrow_token
+    #[account(constraint = escrow.key() == config_account.load()?.escrow @ LockFundEscrowError::InvalidEscrow)]
+    pub escrow: AccountInfo<'info>,
+
+    /// CHECK: Escrow Token Account.
+    #[account(
+        mut,
+        associated_token::mint = mint_token,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub escrow_token: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Recipient Token Account.
+    #[account(
+        mut,
+        associated_token::mint = mint_token,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program
+    )]
+    pub recipient_token: InterfaceAccount<'info, TokenAccount>,
+
+    pub recipient: Signer<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint_token: InterfaceAccount<'info, Mint>,
+
+    /// Withdrawal accounting of mint_token.
+    #[account(
+        mut,
+        seeds = [
+            MINT_LIMIT_SEED.as_ref(),
+            config_account.key().as_ref(),
+            mint_token.key().as_ref(),
+        ],
+        bump = mint_limit.bump
+    )]
+    pub mint_limit: Account<'info, MintLimit>,
+
+    /// Token program.
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Recipient sweeps the whole escrow token balance once the authority missed
+/// heartbeats for `inactivity_period`. Neither approvers nor the daily limit
+/// are involved, the cliff and the vested balance still apply. Pausing does not
+/// block recovery, an inactive authority could otherwise lock the funds forever.
+/// Remaining accounts are the transfer hook accounts of mint_token.
+pub fn recover_funds_token_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, RecoverFundsToken<'info>>,
+) -> Result<()> {
+    let mut config_account = ctx.accounts.config_account.load_mut()?;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    config_account.validate_recoverable(current_time)?;
+    config_account.validate_mint(&ctx.accounts.mint_token.key())?;
+    config_account.validate_cliff_time(current_time)?;
+
+    let mint = ctx.accounts.mint_token.key();
+    let mut amount = ctx.accounts.escrow_token.amount;
+    if let Some(available) = config_account.available_vested_amount(current_time, &mint) {
+        amount = amount.min(available);
+    }
+    if let Some(vested_amount) = config_account.consume_vesting(current_time, &mint, amount)? {
+        emit_cpi!(VestedBalanceEvent {
+            config_account: ctx.accounts.config_account.key(),
+            mint,
+            vested_amount,
+            withdrawn_amount: config_account.vesting_withdrawn,
+        });
+    }
+    config_account.record_withdrawal(current_time, 0)?;
+    ctx.accounts.mint_limit.record_withdrawal(amount)?;
+    let escrow_seeds = escrow_seeds!(config_account);
+
+    let net_amount = transfer_checked_from_escrow(
+        &ctx.accounts.token_program,
+        TransferChecked {
+            from: ctx.accounts.escrow_token.to_account_info(),
+            mint: ctx.accounts.mint_token.to_account_info(),
+            to: ctx.accounts.recipient_token.to_account_info(),
+            authority: ctx.accounts.escrow.to_account_info(),
+        },
+        ctx.remaining_accounts,
+        &[&escrow_seeds[..]],
+        amount,
+        ctx.accounts.mint_token.decimals,
+    )?;
+
+    emit_cpi!(TransferEvent {
+        from: ctx.accounts.escrow_token.key(),
+        to: ctx.accounts.recipient_token.key(),
+        recipient: Some(ctx.accounts.recipient.key()),
+        config_account: ctx.accounts.config_account.key(),
+        amount,
+        net_amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use crate::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RefundSol<'info> {
+    #[account(mut, has_one = authority)]
+    pub config_account: AccountLoader<'info, ConfigAccount>,
+
+    /// CHECK: account will transfer fund
+    #[account(mut, constraint = escrow.key() == config_account.load()?.escrow @ LockFundEscrowError::InvalidEscrow)]
+    pub escrow: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Approver, other approvers sign as remaining accounts.
+    pub approver: Signer<'info>,
+    /// system program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Claw SOL back to the authority once `refund_time` has passed. The daily limit
+/// applies as on the recipient path, the vested SOL not withdrawn yet stays in the
+/// escrow.
+pub fn refund_sol_handler(ctx: Context<RefundSol>, amount: u64) -> Result<()> {
+    let mut config_account = ctx.accounts.config_account.load_mut()?;
+    config_account.verify_approvals(&ctx.accounts.approver, ctx.remaining_accounts)?;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    config_account.validate_not_paused()?;
+    config_account.validate_refund_time(current_time)?;
+    config_account.consume_daily_limit(current_time, amount)?;
+    let balance = ctx
+        .accounts
+        .escrow
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(0));
+    config_account.validate_refund_amount(current_time, &Pubkey::default(), balance, amount)?;
+    config_account.record_withdrawal(current_time, amount)?;
+    let escrow_seeds = escrow_seeds!(config_account);
+
+    transfer_sol_from_escrow(
+        &ctx.accounts.system_program,
+        ctx.accounts.escrow.to_account_info(),
+        ctx.accounts.authority.to_account_info(),
+        &[&escrow_seeds[..]],
+        amount,
+    )?;
+
+    emit_cpi!(RefundEvent {
+        from: ctx.accounts.escrow.key(),
+        to: ctx.accounts.authority.key(),
+        config_account: ctx.accounts.config_account.key(),
+        mint: Pubkey::default(),
+        amount,
+        net_amount: amount,
+    });
+
+    Ok(())
+}
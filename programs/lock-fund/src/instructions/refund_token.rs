@@ -0,0 +1,110 @@
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RefundToken<'info> {
+    #[account(mut, has_one = authority)]
+    pub config_account: AccountLoader<'info, ConfigAccount>,
+
+    /// CHECK: This account use to validate escrow_token
+    #[account(constraint = escrow.key() == config_account.load()?.escrow @ LockFundEscrowError::InvalidEscrow)]
+    pub escrow: AccountInfo<'info>,
+
+    /// CHECK: Escrow Token Account.
+    #[account(
+        mut,
+        associated_token::mint = mint_token,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub escrow_token: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Authority Token Account.
+    #[account(
+        mut,
+        associated_token::mint = mint_token,
+        associated_token::authority = authority,
+        associated_token::token_program = token_program
+    )]
+    pub authority_token: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint_token: InterfaceAccount<'info, Mint>,
+
+    /// Withdrawal accounting of mint_token.
+    #[account(
+        mut,
+        seeds = [
+            MINT_LIMIT_SEED.as_ref(),
+            config_account.key().as_ref(),
+            mint_token.key().as_ref(),
+        ],
+        bump = mint_limit.bump
+    )]
+    pub mint_limit: Account<'info, MintLimit>,
+
+    pub authority: Signer<'info>,
+
+    /// Approver, other approvers sign as the first `approver_count` remaining accounts.
+    pub approver: Signer<'info>,
+    /// Token program.
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Claw tokens back to the authority once `refund_time` has passed. The daily limit of
+/// the mint applies as on the recipient path, the vested tokens not withdrawn yet stay
+/// in the escrow. Remaining accounts are `approver_count` other approver signers
+/// followed by the transfer hook accounts.
+pub fn refund_token_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, RefundToken<'info>>,
+    amount: u64,
+    approver_count: u8,
+) -> Result<()> {
+    let mut config_account = ctx.accounts.config_account.load_mut()?;
+    let (approver_accounts, hook_accounts) =
+        split_approver_accounts(ctx.remaining_accounts, approver_count)?;
+    config_account.verify_approvals(&ctx.accounts.approver, approver_accounts)?;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    config_account.validate_not_paused()?;
+    config_account.validate_mint(&ctx.accounts.mint_token.key())?;
+    config_account.validate_refund_time(current_time)?;
+    ctx.accounts
+        .mint_limit
+        .consume_daily_limit(current_time, amount)?;
+    config_account.validate_refund_amount(
+        current_time,
+        &ctx.accounts.mint_token.key(),
+        ctx.accounts.escrow_token.amount,
+        amount,
+    )?;
+    config_account.record_withdrawal(current_time, 0)?;
+    ctx.accounts.mint_limit.record_withdrawal(amount)?;
+    let escrow_seeds = escrow_seeds!(config_account);
+
+    let net_amount = transfer_checked_from_escrow(
+        &ctx.accounts.token_program,
+        TransferChecked {
+            from: ctx.accounts.escrow_token.to_account_info(),
+            mint: ctx.accounts.mint_token.to_account_info(),
+            to: ctx.accounts.authority_token.to_account_info(),
+            authority: ctx.accounts.escrow.to_account_info(),
+        },
+        hook_accounts,
+        &[&escrow_seeds[..]],
+        amount,
+        ctx.accounts.mint_token.decimals,
+    )?;
+
+    emit_cpi!(RefundEvent {
+        from: ctx.accounts.escrow_token.key(),
+        to: ctx.accounts.authority_token.key(),
+        config_account: ctx.accounts.config_account.key(),
+        mint: ctx.accounts.mint_token.key(),
+        amount,
+        net_amount,
+    });
+
+    Ok(())
+}
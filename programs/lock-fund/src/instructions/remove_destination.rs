@@ -0,0 +1,29 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct RemoveDestination<'info> {
+    #[account(mut, has_one = authority)]
+    pub config_account: AccountLoader<'info, ConfigAccount>,
+
+    #[account(mut, close = authority, has_one = config_account)]
+    pub destination_entry: Account<'info, Destination>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Approver, other approvers sign as remaining accounts.
+    pub approver: Signer<'info>,
+}
+
+pub fn remove_destination_handler(ctx: Context<RemoveDestination>) -> Result<()> {
+    let mut config_account = ctx.accounts.config_account.load_mut()?;
+    config_account.verify_approvals(&ctx.accounts.approver, ctx.remaining_accounts)?;
+    config_account.destination_count -= 1;
+
+    emit!(DestinationRemovedEvent {
+        config_account: ctx.accounts.config_account.key(),
+        destination: ctx.accounts.destination_entry.destination,
+    });
+
+    Ok(())
+}
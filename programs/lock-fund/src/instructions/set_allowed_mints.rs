@@ -0,0 +1,35 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetAllowedMints<'info> {
+    #[account(mut, has_one = authority)]
+    pub config_account: AccountLoader<'info, ConfigAccount>,
+
+    pub authority: Signer<'info>,
+
+    /// Approver, other approvers sign as remaining accounts.
+    pub approver: Signer<'info>,
+}
+
+/// Replace the mint allowlist, an empty list allows any mint.
+pub fn set_allowed_mints_handler(
+    ctx: Context<SetAllowedMints>,
+    allowed_mints: Vec<Pubkey>,
+) -> Result<()> {
+    let mut config_account = ctx.accounts.config_account.load_mut()?;
+    config_account.verify_approvals(&ctx.accounts.approver, ctx.remaining_accounts)?;
+    require!(
+        config_account.update_actor_mode & UpdateActorMode::Mints as u8 != 0,
+        LockFundEscrowError::ActorNotUpdatable
+    );
+    validate_allowed_mints(&allowed_mints)?;
+
+    config_account.set_allowed_mints(&allowed_mints);
+
+    emit!(AllowedMintsUpdatedEvent {
+        config_account: ctx.accounts.config_account.key(),
+        allowed_mints,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetApprovers<'info> {
+    #[account(mut, has_one = authority)]
+    pub config_account: AccountLoader<'info, ConfigAccount>,
+
+    pub authority: Signer<'info>,
+
+    /// Approver, other approvers sign as remaining accounts.
+    pub approver: Signer<'info>,
+}
+
+/// Replace the approver set, requires the threshold of the current set.
+pub fn set_approvers_handler(
+    ctx: Context<SetApprovers>,
+    approvers: Vec<Pubkey>,
+    approver_threshold: u8,
+) -> Result<()> {
+    let mut config_account = ctx.accounts.config_account.load_mut()?;
+    config_account.verify_approvals(&ctx.accounts.approver, ctx.remaining_accounts)?;
+    require!(
+        config_account.update_actor_mode & UpdateActorMode::Approver as u8 != 0,
+        LockFundEscrowError::ActorNotUpdatable
+    );
+    validate_approvers(&config_account.authority, &approvers, approver_threshold)?;
+
+    config_account.set_approvers(&approvers, approver_threshold);
+
+    emit!(ApproversUpdatedEvent {
+        config_account: ctx.accounts.config_account.key(),
+        approvers,
+        approver_threshold,
+    });
+
+    Ok(())
+}
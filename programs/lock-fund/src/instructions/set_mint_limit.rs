@@ -0,0 +1,63 @@
+use anchor_spl::token_interface::Mint;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetMintLimit<'info> {
+    #[account(mut, has_one = authority)]
+    pub config_account: AccountLoader<'info, ConfigAccount>,
+
+    #[account(
+        init_if_needed,
+        seeds = [
+            MINT_LIMIT_SEED.as_ref(),
+            config_account.key().as_ref(),
+            mint_token.key().as_ref(),
+        ],
+        bump,
+        payer = authority,
+        space = 8 + MintLimit::INIT_SPACE
+    )]
+    pub mint_limit: Account<'info, MintLimit>,
+
+    pub mint_token: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Approver, other approvers sign as remaining accounts.
+    pub approver: Signer<'info>,
+    /// system program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Create or update the daily limit and queue threshold of a mint. Token withdrawals
+/// of a mint are rejected until its limit is set, the config-level `amount_per_day`
+/// and `queue_threshold` only cover SOL.
+pub fn set_mint_limit_handler(
+    ctx: Context<SetMintLimit>,
+    amount_per_day: u64,
+    queue_threshold: u64,
+) -> Result<()> {
+    let mut config_account = ctx.accounts.config_account.load_mut()?;
+    config_account.verify_approvals(&ctx.accounts.approver, ctx.remaining_accounts)?;
+
+    let mint_limit = &mut ctx.accounts.mint_limit;
+    if mint_limit.config_account == Pubkey::default() {
+        config_account.mint_limit_count += 1;
+    }
+    mint_limit.config_account = ctx.accounts.config_account.key();
+    mint_limit.mint = ctx.accounts.mint_token.key();
+    mint_limit.amount_per_day = amount_per_day;
+    mint_limit.queue_threshold = queue_threshold;
+    mint_limit.bump = ctx.bumps.mint_limit;
+
+    emit!(SetMintLimitEvent {
+        config_account: ctx.accounts.config_account.key(),
+        mint: ctx.accounts.mint_token.key(),
+        amount_per_day,
+        queue_threshold,
+    });
+
+    Ok(())
+}
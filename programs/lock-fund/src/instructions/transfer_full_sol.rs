@@ -0,0 +1,73 @@
+use crate::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct TransferFullSol<'info> {
+    #[account(mut, has_one = authority)]
+    pub config_account: AccountLoader<'info, ConfigAccount>,
+
+    /// CHECK: account will transfer fund
+    #[account(mut, constraint = escrow.key() == config_account.load()?.escrow @ LockFundEscrowError::InvalidEscrow)]
+    pub escrow: AccountInfo<'info>,
+
+    /// CHECK: account will receive fund
+    #[account(mut, constraint = recipient.key() == config_account.load()?.recipient @ LockFundEscrowError::InvalidRecipient)]
+    pub recipient: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Approver, other approvers sign as remaining accounts.
+    pub approver: Signer<'info>,
+    /// system program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Move every lamport above the rent-exempt reserve of the escrow to the recipient.
+/// The daily limit does not apply to this path, the queue threshold does.
+pub fn transfer_full_sol_handler(ctx: Context<TransferFullSol>) -> Result<()> {
+    let mut config_account = ctx.accounts.config_account.load_mut()?;
+    config_account.verify_approvals(&ctx.accounts.approver, ctx.remaining_accounts)?;
+    config_account.validate_transfer_full()?;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    config_account.validate_not_paused()?;
+    config_account.validate_cliff_time(current_time)?;
+
+    let amount = ctx
+        .accounts
+        .escrow
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(0));
+    config_account.validate_queue_threshold(amount)?;
+    if let Some(vested_amount) =
+        config_account.consume_vesting(current_time, &Pubkey::default(), amount)?
+    {
+        emit_cpi!(VestedBalanceEvent {
+            config_account: ctx.accounts.config_account.key(),
+            mint: Pubkey::default(),
+            vested_amount,
+            withdrawn_amount: config_account.vesting_withdrawn,
+        });
+    }
+    config_account.record_withdrawal(current_time, amount)?;
+    let escrow_seeds = escrow_seeds!(config_account);
+
+    transfer_sol_from_escrow(
+        &ctx.accounts.system_program,
+        ctx.accounts.escrow.to_account_info(),
+        ctx.accounts.recipient.to_account_info(),
+        &[&escrow_seeds[..]],
+        amount,
+    )?;
+
+    emit_cpi!(TransferEvent {
+        from: ctx.accounts.escrow.key(),
+        to: ctx.accounts.recipient.key(),
+        recipient: None,
+        config_account: ctx.accounts.config_account.key(),
+        amount,
+        net_amount: amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,136 @@
+use anchor_spl::token_interface::{
+    close_account, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct TransferFullToken<'info> {
+    #[account(mut, has_one = authority)]
+    pub config_account: AccountLoader<'info, ConfigAccount>,
+
+    /// CHECK: This account use to validate escrow_token
+    #[account(constraint = escrow.key() == config_account.load()?.escrow @ LockFundEscrowError::InvalidEscrow)]
+    pub escrow: AccountInfo<'info>,
+
+    /// CHECK: Escrow Token Account.
+    #[account(
+        mut,
+        associated_token::mint = mint_token,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub escrow_token: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Recipient Token Account.
+    #[account(
+        mut,
+        associated_token::mint = mint_token,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program
+    )]
+    pub recipient_token: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: This account use to validate transfer recipient
+    #[account(mut, constraint = recipient.key() == config_account.load()?.recipient @ LockFundEscrowError::InvalidRecipient)]
+    pub recipient: AccountInfo<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint_token: InterfaceAccount<'info, Mint>,
+
+    /// Withdrawal accounting of mint_token.
+    #[account(
+        mut,
+        seeds = [
+            MINT_LIMIT_SEED.as_ref(),
+            config_account.key().as_ref(),
+            mint_token.key().as_ref(),
+        ],
+        bump = mint_limit.bump
+    )]
+    pub mint_limit: Account<'info, MintLimit>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Approver, other approvers sign as the first `approver_count` remaining accounts.
+    pub approver: Signer<'info>,
+    /// Token program.
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Move the whole escrow token balance to the recipient, optionally closing the
+/// escrow token account and returning its rent to the authority.
+/// The daily limit does not apply to this path, the queue threshold does. Remaining
+/// accounts are `approver_count` other approver signers followed by the transfer hook
+/// accounts.
+pub fn transfer_full_token_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferFullToken<'info>>,
+    close_escrow_token: bool,
+    approver_count: u8,
+) -> Result<()> {
+    let mut config_account = ctx.accounts.config_account.load_mut()?;
+    let (approver_accounts, hook_accounts) =
+        split_approver_accounts(ctx.remaining_accounts, approver_count)?;
+    config_account.verify_approvals(&ctx.accounts.approver, approver_accounts)?;
+    config_account.validate_transfer_full()?;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    config_account.validate_not_paused()?;
+    config_account.validate_mint(&ctx.accounts.mint_token.key())?;
+    config_account.validate_cliff_time(current_time)?;
+
+    let amount = ctx.accounts.escrow_token.amount;
+    ctx.accounts.mint_limit.validate_queue_threshold(amount)?;
+    if let Some(vested_amount) =
+        config_account.consume_vesting(current_time, &ctx.accounts.mint_token.key(), amount)?
+    {
+        emit_cpi!(VestedBalanceEvent {
+            config_account: ctx.accounts.config_account.key(),
+            mint: ctx.accounts.mint_token.key(),
+            vested_amount,
+            withdrawn_amount: config_account.vesting_withdrawn,
+        });
+    }
+    config_account.record_withdrawal(current_time, 0)?;
+    ctx.accounts.mint_limit.record_withdrawal(amount)?;
+    let escrow_seeds = escrow_seeds!(config_account);
+    let token_program = &ctx.accounts.token_program;
+
+    let net_amount = transfer_checked_from_escrow(
+        token_program,
+        TransferChecked {
+            from: ctx.accounts.escrow_token.to_account_info(),
+            mint: ctx.accounts.mint_token.to_account_info(),
+            to: ctx.accounts.recipient_token.to_account_info(),
+            authority: ctx.accounts.escrow.to_account_info(),
+        },
+        hook_accounts,
+        &[&escrow_seeds[..]],
+        amount,
+        ctx.accounts.mint_token.decimals,
+    )?;
+
+    if close_escrow_token {
+        close_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.escrow_token.to_account_info(),
+                destination: ctx.accounts.authority.to_account_info(),
+                authority: ctx.accounts.escrow.to_account_info(),
+            },
+            &[&escrow_seeds[..]],
+        ))?;
+    }
+
+    emit_cpi!(TransferEvent {
+        from: ctx.accounts.escrow_token.key(),
+        to: ctx.accounts.recipient_token.key(),
+        recipient: Some(ctx.accounts.recipient.key()),
+        config_account: ctx.accounts.config_account.key(),
+        amount,
+        net_amount,
+    });
+
+    Ok(())
+}
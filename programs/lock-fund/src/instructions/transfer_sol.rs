@@ -4,47 +4,96 @@ use crate::*;
 #[derive(Accounts)]
 pub struct TransferSol<'info> {
 
-    #[account(mut, has_one = authority, has_one = approver)]
+    #[account(mut, has_one = authority)]
     pub config_account: AccountLoader<'info, ConfigAccount>,
 
     /// CHECK: account will transfer fund
     #[account(mut, constraint = escrow.key() == config_account.load()?.escrow @ LockFundEscrowError::InvalidEscrow)]
     pub escrow: AccountInfo<'info>,
 
-    /// CHECK: account will receive fund
-    #[account(mut, constraint = recipient.key() == config_account.load()?.recipient @ LockFundEscrowError::InvalidRecipient)]
+    /// CHECK: account will receive fund, validated against the config recipient and destination
+    #[account(mut)]
     pub recipient: AccountInfo<'info>,
 
+    /// Allowlist entry of recipient, required when recipient is not the config recipient.
+    #[account(
+        seeds = [
+            DESTINATION_SEED.as_ref(),
+            config_account.key().as_ref(),
+            recipient.key().as_ref(),
+        ],
+        bump = destination.bump
+    )]
+    pub destination: Option<Account<'info, Destination>>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    /// Approver, other approvers sign as remaining accounts.
     pub approver: Signer<'info>,
     /// system program.
     pub system_program: Program<'info, System>,
 }
 
 pub fn transfer_sol_handler(ctx: Context<TransferSol>, amount: u64) -> Result<()> {
-    let config_account = ctx.accounts.config_account.load()?;
+    let mut config_account = ctx.accounts.config_account.load_mut()?;
+    config_account.verify_approvals(&ctx.accounts.approver, ctx.remaining_accounts)?;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    config_account.validate_not_paused()?;
+    config_account.validate_recipient(
+        ctx.accounts.recipient.key,
+        ctx.accounts.destination.as_deref(),
+        current_time,
+    )?;
+    config_account.validate_cliff_time(current_time)?;
+    config_account.validate_queue_threshold(amount)?;
+    config_account.consume_daily_limit(current_time, amount)?;
+    if let Some(vested_amount) =
+        config_account.consume_vesting(current_time, &Pubkey::default(), amount)?
+    {
+        emit_cpi!(VestedBalanceEvent {
+            config_account: ctx.accounts.config_account.key(),
+            mint: Pubkey::default(),
+            vested_amount,
+            withdrawn_amount: config_account.vesting_withdrawn,
+        });
+    }
+    config_account.record_withdrawal(current_time, amount)?;
     let escrow_seeds = escrow_seeds!(config_account);
 
-    anchor_lang::system_program::transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.system_program.to_account_info(),
-            anchor_lang::system_program::Transfer {
-                from: ctx.accounts.escrow.to_account_info(),
-                to: ctx.accounts.recipient.to_account_info(),
-            },
-            &[&escrow_seeds[..]],
-        ),
+    transfer_sol_from_escrow(
+        &ctx.accounts.system_program,
+        ctx.accounts.escrow.to_account_info(),
+        ctx.accounts.recipient.to_account_info(),
+        &[&escrow_seeds[..]],
         amount,
     )?;
 
     emit_cpi!(TransferEvent {
         from: ctx.accounts.escrow.key(),
         to: ctx.accounts.recipient.key(),
+        recipient: None,
         config_account: ctx.accounts.config_account.key(),
-        amount: amount
+        amount: amount,
+        net_amount: amount,
     });
 
     Ok(())
 }
+
+pub fn transfer_sol_from_escrow<'info>(
+    system_program: &Program<'info, System>,
+    escrow: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    amount: u64,
+) -> Result<()> {
+    anchor_lang::system_program::transfer(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            anchor_lang::system_program::Transfer { from: escrow, to },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
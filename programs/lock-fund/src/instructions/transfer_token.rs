@@ -1,6 +1,14 @@
 use anchor_spl::{
-    token::{TransferChecked, ID},
-    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked as TransferChecked2022},
+    associated_token::AssociatedToken,
+    token::ID,
+    token_2022::spl_token_2022::{
+        extension::{
+            transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions,
+        },
+        onchain,
+        state::Mint as MintState,
+    },
+    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked},
 };
 
 use crate::*;
@@ -9,88 +17,215 @@ use crate::*;
 #[derive(Accounts)]
 pub struct TransferToken<'info> {
     /// Escrow.
-    #[account(mut)]
+    #[account(mut, has_one = authority)]
     pub config_account: AccountLoader<'info, ConfigAccount>,
 
     /// CHECK: This account use to validate escrow_token
-    /// #[account(mut, constraint = escrow.key() == config_account.load()?.escrow @ LockFundEscrowError::InvalidEscrow)]
+    #[account(constraint = escrow.key() == config_account.load()?.escrow @ LockFundEscrowError::InvalidEscrow)]
     pub escrow: AccountInfo<'info>,
 
     /// CHECK: Escrow Token Account.
     #[account(
         mut,
         associated_token::mint = mint_token,
-        associated_token::authority = escrow
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
     )]
     pub escrow_token: InterfaceAccount<'info, TokenAccount>,
 
-    /// CHECK: Recipient Token Account.
+    /// Recipient Token Account, created and paid by the authority when missing.
     #[account(
-        mut,
+        init_if_needed,
+        payer = authority,
         associated_token::mint = mint_token,
-        associated_token::authority = recipient
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program
     )]
     pub recipient_token: InterfaceAccount<'info, TokenAccount>,
 
-    /// CHECK: This account use to validate transfer recipient
-    #[account(mut, constraint = recipient.key() == config_account.load()?.recipient @ LockFundEscrowError::InvalidRecipient)]
+    /// CHECK: This account use to validate transfer recipient, config recipient or destination
+    #[account(mut)]
     pub recipient: AccountInfo<'info>,
 
+    /// Allowlist entry of recipient, required when recipient is not the config recipient.
+    #[account(
+        seeds = [
+            DESTINATION_SEED.as_ref(),
+            config_account.key().as_ref(),
+            recipient.key().as_ref(),
+        ],
+        bump = destination.bump
+    )]
+    pub destination: Option<Account<'info, Destination>>,
+
     #[account(mint::token_program = token_program)]
     pub mint_token: InterfaceAccount<'info, Mint>,
 
+    /// Daily withdrawal limit of mint_token, must be set by set_mint_limit first.
+    #[account(
+        mut,
+        seeds = [
+            MINT_LIMIT_SEED.as_ref(),
+            config_account.key().as_ref(),
+            mint_token.key().as_ref(),
+        ],
+        bump = mint_limit.bump
+    )]
+    pub mint_limit: Account<'info, MintLimit>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    /// Approver, other approvers sign as the first `approver_count` remaining accounts.
     pub approver: Signer<'info>,
     /// Token program.
     pub token_program: Interface<'info, TokenInterface>,
+    /// Associated token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// system program.
+    pub system_program: Program<'info, System>,
 }
 
-pub fn transfer_token_handler(ctx: Context<TransferToken>, amount: u64) -> Result<()> {
-    let config_account = ctx.accounts.config_account.load()?;
+/// Remaining accounts are `approver_count` other approver signers followed by the
+/// transfer hook accounts of mint_token.
+pub fn transfer_token_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferToken<'info>>,
+    amount: u64,
+    approver_count: u8,
+) -> Result<()> {
+    let mut config_account = ctx.accounts.config_account.load_mut()?;
+    let (approver_accounts, hook_accounts) =
+        split_approver_accounts(ctx.remaining_accounts, approver_count)?;
+    config_account.verify_approvals(&ctx.accounts.approver, approver_accounts)?;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    config_account.validate_not_paused()?;
+    config_account.validate_mint(&ctx.accounts.mint_token.key())?;
+    config_account.validate_recipient(
+        ctx.accounts.recipient.key,
+        ctx.accounts.destination.as_deref(),
+        current_time,
+    )?;
+    config_account.validate_cliff_time(current_time)?;
+    ctx.accounts.mint_limit.validate_queue_threshold(amount)?;
+    ctx.accounts
+        .mint_limit
+        .consume_daily_limit(current_time, amount)?;
+    if let Some(vested_amount) =
+        config_account.consume_vesting(current_time, &ctx.accounts.mint_token.key(), amount)?
+    {
+        emit_cpi!(VestedBalanceEvent {
+            config_account: ctx.accounts.config_account.key(),
+            mint: ctx.accounts.mint_token.key(),
+            vested_amount,
+            withdrawn_amount: config_account.vesting_withdrawn,
+        });
+    }
+    config_account.record_withdrawal(current_time, 0)?;
+    ctx.accounts.mint_limit.record_withdrawal(amount)?;
     let escrow_seeds = escrow_seeds!(config_account);
     let token_program = &ctx.accounts.token_program;
 
-    let decimals = ctx.accounts.mint_token.decimals;
+    let net_amount = transfer_checked_from_escrow(
+        token_program,
+        TransferChecked {
+            from: ctx.accounts.escrow_token.to_account_info(),
+            mint: ctx.accounts.mint_token.to_account_info(),
+            to: ctx.accounts.recipient_token.to_account_info(),
+            authority: ctx.accounts.escrow.to_account_info(),
+        },
+        hook_accounts,
+        &[&escrow_seeds[..]],
+        amount,
+        ctx.accounts.mint_token.decimals,
+    )?;
+
+    emit_cpi!(TransferEvent {
+        from: ctx.accounts.escrow_token.key(),
+        to: ctx.accounts.recipient_token.key(),
+        recipient: Some(ctx.accounts.recipient.key()),
+        config_account: ctx.accounts.config_account.key(),
+        amount: amount,
+        net_amount,
+    });
+
+    Ok(())
+}
+
+/// Transfer from an escrow token account, returning the amount `to` receives.
+/// Token-2022 transfer fees are withheld from `amount`, transfer hook accounts are
+/// resolved from `hook_accounts`. Signers among them sign the transfer, so approver
+/// signers must never be passed here.
+pub fn transfer_checked_from_escrow<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    accounts: TransferChecked<'info>,
+    hook_accounts: &[AccountInfo<'info>],
+    signer_seeds: &[&[&[u8]]],
+    amount: u64,
+    decimals: u8,
+) -> Result<u64> {
     if token_program.key().eq(&ID.key()) {
         anchor_spl::token::transfer_checked(
             CpiContext::new_with_signer(
                 token_program.to_account_info(),
-                TransferChecked {
-                    from: ctx.accounts.escrow_token.to_account_info(),
-                    mint: ctx.accounts.mint_token.to_account_info(),
-                    to: ctx.accounts.recipient_token.to_account_info(),
-                    authority: ctx.accounts.escrow.to_account_info(),
+                anchor_spl::token::TransferChecked {
+                    from: accounts.from,
+                    mint: accounts.mint,
+                    to: accounts.to,
+                    authority: accounts.authority,
                 },
-                &[&escrow_seeds[..]],
-            ),
-            amount,
-            decimals,
-        )?;
-    } else {
-        anchor_spl::token_2022::transfer_checked(
-            CpiContext::new_with_signer(
-                token_program.to_account_info(),
-                TransferChecked2022 {
-                    from: ctx.accounts.escrow_token.to_account_info(),
-                    mint: ctx.accounts.mint_token.to_account_info(),
-                    to: ctx.accounts.recipient_token.to_account_info(),
-                    authority: ctx.accounts.escrow.to_account_info(),
-                },
-                &[&escrow_seeds[..]],
+                signer_seeds,
             ),
             amount,
             decimals,
         )?;
+
+        return Ok(amount);
     }
 
-    emit_cpi!(TransferEvent {
-        from: ctx.accounts.escrow_token.key(),
-        to: ctx.accounts.recipient_token.key(),
-        config_account: ctx.accounts.config_account.key(),
-        amount: amount
-    });
+    let fee = transfer_fee(&accounts.mint, amount)?;
+    onchain::invoke_transfer_checked(
+        &token_program.key(),
+        accounts.from,
+        accounts.mint,
+        accounts.to,
+        accounts.authority,
+        hook_accounts,
+        amount,
+        decimals,
+        signer_seeds,
+    )?;
 
-    Ok(())
+    let net_amount = amount
+        .checked_sub(fee)
+        .ok_or(LockFundEscrowError::MathOverflow)?;
+
+    Ok(net_amount)
+}
+
+/// Split remaining accounts into the first `approver_count` approver signers and the
+/// transfer hook accounts that follow them.
+pub fn split_approver_accounts<'a, 'info>(
+    remaining_accounts: &'a [AccountInfo<'info>],
+    approver_count: u8,
+) -> Result<(&'a [AccountInfo<'info>], &'a [AccountInfo<'info>])> {
+    require!(
+        approver_count as usize <= remaining_accounts.len(),
+        LockFundEscrowError::InvalidApproverCount
+    );
+
+    Ok(remaining_accounts.split_at(approver_count as usize))
+}
+
+/// Token-2022 transfer fee of `amount` at the current epoch, 0 without the extension.
+pub fn transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    let mint_data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+    let Ok(transfer_fee_config) = mint.get_extension::<TransferFeeConfig>() else {
+        return Ok(0);
+    };
+    let fee = transfer_fee_config
+        .calculate_epoch_fee(Clock::get()?.epoch, amount)
+        .ok_or(LockFundEscrowError::MathOverflow)?;
+
+    Ok(fee)
 }
@@ -0,0 +1,24 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct Unpause<'info> {
+    #[account(mut, has_one = authority)]
+    pub config_account: AccountLoader<'info, ConfigAccount>,
+
+    pub authority: Signer<'info>,
+
+    /// Approver, other approvers sign as remaining accounts.
+    pub approver: Signer<'info>,
+}
+
+pub fn unpause_handler(ctx: Context<Unpause>) -> Result<()> {
+    let mut config_account = ctx.accounts.config_account.load_mut()?;
+    config_account.verify_approvals(&ctx.accounts.approver, ctx.remaining_accounts)?;
+    config_account.paused = 0;
+
+    emit!(EscrowUnpausedEvent {
+        config_account: ctx.accounts.config_account.key(),
+    });
+
+    Ok(())
+}
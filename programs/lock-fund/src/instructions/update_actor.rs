@@ -0,0 +1,30 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct UpdateActor<'info> {
+    #[account(mut, has_one = authority)]
+    pub config_account: AccountLoader<'info, ConfigAccount>,
+
+    pub authority: Signer<'info>,
+
+    /// Approver, other approvers sign as remaining accounts.
+    pub approver: Signer<'info>,
+}
+
+pub fn update_actor_handler(ctx: Context<UpdateActor>, mode: u8, new_actor: Pubkey) -> Result<()> {
+    let update_actor_mode =
+        UpdateActorMode::try_from(mode).map_err(|_| LockFundEscrowError::InvalidActorMode)?;
+
+    let mut config_account = ctx.accounts.config_account.load_mut()?;
+    config_account.verify_approvals(&ctx.accounts.approver, ctx.remaining_accounts)?;
+    let old_actor = config_account.update_actor(update_actor_mode, new_actor)?;
+
+    emit!(ConfigUpdatedEvent {
+        config_account: ctx.accounts.config_account.key(),
+        update_actor_mode: mode,
+        old_actor,
+        new_actor,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct VetoWithdrawal<'info> {
+    #[account(mut, has_one = authority)]
+    pub config_account: AccountLoader<'info, ConfigAccount>,
+
+    #[account(mut, close = authority, has_one = config_account)]
+    pub proposal: Account<'info, WithdrawalProposal>,
+
+    /// CHECK: receives the proposal rent back.
+    #[account(mut)]
+    pub authority: AccountInfo<'info>,
+
+    pub approver: Signer<'info>,
+}
+
+/// Cancel a pending withdrawal, any single approver can veto.
+pub fn veto_withdrawal_handler(ctx: Context<VetoWithdrawal>) -> Result<()> {
+    let mut config_account = ctx.accounts.config_account.load_mut()?;
+    require!(
+        config_account.is_approver(ctx.accounts.approver.key),
+        LockFundEscrowError::Unauthorize
+    );
+    config_account.open_proposal_count -= 1;
+
+    emit!(WithdrawalVetoedEvent {
+        config_account: ctx.accounts.config_account.key(),
+        proposal: ctx.accounts.proposal.key(),
+        approver: ctx.accounts.approver.key(),
+    });
+
+    Ok(())
+}
@@ -18,7 +18,8 @@ macro_rules! escrow_seeds {
     ($config_account:expr) => {
         &[
             ESCROW_SEED.as_ref(),
-            $config_account.authority.as_ref(),
+            $config_account.escrow_authority.as_ref(),
+            bytemuck::bytes_of(&$config_account.escrow_id),
             &[$config_account.escrow_bump],
         ]
     };
@@ -32,11 +33,183 @@ pub mod lock_fund {
         create_config_handler(ctx, &params)
     }
 
-    pub fn transfer_token(ctx: Context<TransferToken>, amount: u64) -> Result<()> {
-        transfer_token_handler(ctx, amount)
+    pub fn close_config<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CloseConfig<'info>>,
+    ) -> Result<()> {
+        close_config_handler(ctx)
+    }
+
+    pub fn update_actor(ctx: Context<UpdateActor>, mode: u8, new_actor: Pubkey) -> Result<()> {
+        update_actor_handler(ctx, mode, new_actor)
+    }
+
+    pub fn propose_new_authority(
+        ctx: Context<ProposeNewAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        propose_new_authority_handler(ctx, new_authority)
+    }
+
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        accept_authority_handler(ctx)
+    }
+
+    pub fn pause(ctx: Context<Pause>) -> Result<()> {
+        pause_handler(ctx)
+    }
+
+    pub fn unpause(ctx: Context<Unpause>) -> Result<()> {
+        unpause_handler(ctx)
+    }
+
+    pub fn deposit_token(ctx: Context<DepositToken>, amount: u64) -> Result<()> {
+        deposit_token_handler(ctx, amount)
+    }
+
+    pub fn deposit_sol(ctx: Context<DepositSol>, amount: u64) -> Result<()> {
+        deposit_sol_handler(ctx, amount)
+    }
+
+    pub fn set_approvers(
+        ctx: Context<SetApprovers>,
+        approvers: Vec<Pubkey>,
+        approver_threshold: u8,
+    ) -> Result<()> {
+        set_approvers_handler(ctx, approvers, approver_threshold)
+    }
+
+    pub fn set_allowed_mints(
+        ctx: Context<SetAllowedMints>,
+        allowed_mints: Vec<Pubkey>,
+    ) -> Result<()> {
+        set_allowed_mints_handler(ctx, allowed_mints)
+    }
+
+    pub fn set_mint_limit(
+        ctx: Context<SetMintLimit>,
+        amount_per_day: u64,
+        queue_threshold: u64,
+    ) -> Result<()> {
+        set_mint_limit_handler(ctx, amount_per_day, queue_threshold)
+    }
+
+    pub fn add_destination(ctx: Context<AddDestination>) -> Result<()> {
+        add_destination_handler(ctx)
+    }
+
+    pub fn remove_destination(ctx: Context<RemoveDestination>) -> Result<()> {
+        remove_destination_handler(ctx)
+    }
+
+    pub fn transfer_token<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferToken<'info>>,
+        amount: u64,
+        approver_count: u8,
+    ) -> Result<()> {
+        transfer_token_handler(ctx, amount, approver_count)
     }
 
     pub fn transfer_sol(ctx: Context<TransferSol>, amount: u64) -> Result<()> {
         transfer_sol_handler(ctx, amount)
     }
+
+    pub fn distribute_token<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, DistributeToken<'info>>,
+        amount: u64,
+        approver_count: u8,
+    ) -> Result<()> {
+        distribute_token_handler(ctx, amount, approver_count)
+    }
+
+    pub fn distribute_sol<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, DistributeSol<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        distribute_sol_handler(ctx, amount)
+    }
+
+    pub fn claim_token<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimToken<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        claim_token_handler(ctx, amount)
+    }
+
+    pub fn claim_sol(ctx: Context<ClaimSol>, amount: u64) -> Result<()> {
+        claim_sol_handler(ctx, amount)
+    }
+
+    pub fn propose_withdrawal(
+        ctx: Context<ProposeWithdrawal>,
+        mint: Pubkey,
+        amount: u64,
+        expiry: u64,
+    ) -> Result<()> {
+        propose_withdrawal_handler(ctx, mint, amount, expiry)
+    }
+
+    pub fn approve_withdrawal(ctx: Context<ApproveWithdrawal>) -> Result<()> {
+        approve_withdrawal_handler(ctx)
+    }
+
+    pub fn queue_withdrawal(
+        ctx: Context<QueueWithdrawal>,
+        mint: Pubkey,
+        amount: u64,
+        expiry: u64,
+    ) -> Result<()> {
+        queue_withdrawal_handler(ctx, mint, amount, expiry)
+    }
+
+    pub fn veto_withdrawal(ctx: Context<VetoWithdrawal>) -> Result<()> {
+        veto_withdrawal_handler(ctx)
+    }
+
+    pub fn execute_withdrawal_sol(ctx: Context<ExecuteWithdrawalSol>) -> Result<()> {
+        execute_withdrawal_sol_handler(ctx)
+    }
+
+    pub fn execute_withdrawal_token<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteWithdrawalToken<'info>>,
+    ) -> Result<()> {
+        execute_withdrawal_token_handler(ctx)
+    }
+
+    pub fn refund_token<'info>(
+        ctx: Context<'_, '_, '_, 'info, RefundToken<'info>>,
+        amount: u64,
+        approver_count: u8,
+    ) -> Result<()> {
+        refund_token_handler(ctx, amount, approver_count)
+    }
+
+    pub fn refund_sol(ctx: Context<RefundSol>, amount: u64) -> Result<()> {
+        refund_sol_handler(ctx, amount)
+    }
+
+    pub fn heartbeat(ctx: Context<Heartbeat>) -> Result<()> {
+        heartbeat_handler(ctx)
+    }
+
+    pub fn recover_funds_token<'info>(
+        ctx: Context<'_, '_, '_, 'info, RecoverFundsToken<'info>>,
+    ) -> Result<()> {
+        recover_funds_token_handler(ctx)
+    }
+
+    pub fn recover_funds_sol(ctx: Context<RecoverFundsSol>) -> Result<()> {
+        recover_funds_sol_handler(ctx)
+    }
+
+    pub fn transfer_full_token<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferFullToken<'info>>,
+        close_escrow_token: bool,
+        approver_count: u8,
+    ) -> Result<()> {
+        transfer_full_token_handler(ctx, close_escrow_token, approver_count)
+    }
+
+    pub fn transfer_full_sol(ctx: Context<TransferFullSol>) -> Result<()> {
+        transfer_full_sol_handler(ctx)
+    }
 }
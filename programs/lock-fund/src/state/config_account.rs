@@ -1,15 +1,29 @@
 use anchor_lang::prelude::*;
+use num_enum::TryFromPrimitive;
 use static_assertions::const_assert_eq;
 
-use crate::LockFundEscrowError;
+use crate::{
+    consume_daily_limit, Destination, LockFundEscrowError, BPS_DENOMINATOR, DAILY_LIMIT_BUCKETS,
+    MAX_ALLOWED_MINTS, MAX_APPROVERS, MAX_CLIFF_TIME_DURATION, MAX_REFUND_TIME_DURATION,
+    MAX_SHARES,
+};
 
-#[derive(Copy, Clone, PartialEq, Eq)]
+#[derive(Copy, Clone, PartialEq, Eq, TryFromPrimitive)]
 #[repr(u8)]
 pub enum UpdateActorMode {
     None = 0,
     Authority = 1_u8 << 0, // 00000001
     Approver = 1_u8 << 1,  //  00000010
     Recipient = 1_u8 << 2, //  00000100
+    Mints = 1_u8 << 3,     //  00001000
+}
+
+impl UpdateActorMode {
+    // All defined mode bits
+    pub const MASK: u8 = UpdateActorMode::Authority as u8
+        | UpdateActorMode::Approver as u8
+        | UpdateActorMode::Recipient as u8
+        | UpdateActorMode::Mints as u8;
 }
 
 #[account(zero_copy)]
@@ -17,16 +31,71 @@ pub enum UpdateActorMode {
 pub struct ConfigAccount {
     // Authority of this lock fund escrow aka creator
     pub authority: Pubkey,
-    // Multi-sign to increase secure for withdraw fund action
-    pub approver: Pubkey,
+    // Multi-sign to increase secure for withdraw fund action, only the first approver_count are set
+    pub approvers: [Pubkey; MAX_APPROVERS],
     // Recipient fund address
     pub recipient: Pubkey,
     // escrow vault
     pub escrow: Pubkey,
+    // Authority used to derive the escrow vault, stays the same when authority is updated
+    pub escrow_authority: Pubkey,
+    // Token mint released by the vesting schedule, Pubkey::default() for SOL
+    pub vesting_mint: Pubkey,
+    // Recipients of distribute_sol and distribute_token, only the first share_count are set
+    pub share_recipients: [Pubkey; MAX_SHARES],
+    // Token mints the escrow handles, only the first allowed_mint_count are set
+    pub allowed_mints: [Pubkey; MAX_ALLOWED_MINTS],
+    // Authority proposed by propose_new_authority, takes over once it signs accept_authority
+    pub pending_authority: Pubkey,
+    // Caller-chosen id allows one authority to own multiple escrows
+    pub escrow_id: u64,
     /// Cliff time: After the cliff time, the actor can withdraw funds
     pub cliff_time: u64,
-    // Max amount that can be withdrawn per day to prevent draining all funds in case of a vulnerability
+    // Max lamports that can be withdrawn per day to prevent draining all funds in case of a vulnerability.
+    // SPL tokens are limited per mint by `MintLimit`
     pub amount_per_day: u64,
+    // Hour (unix time / 3600) of the last withdrawal counted against amount_per_day
+    pub last_withdrawal_hour: u64,
+    // Number of withdrawal proposals created, used as next proposal id
+    pub proposal_count: u64,
+    // Withdrawal proposals not executed or vetoed yet
+    pub open_proposal_count: u64,
+    // MintLimit accounts created for this config
+    pub mint_limit_count: u64,
+    // Destination accounts created for this config
+    pub destination_count: u64,
+    // Lamports deposited through deposit_sol
+    pub total_deposited_lamports: u64,
+    // Lamports ever withdrawn from the escrow
+    pub total_withdrawn_lamports: u64,
+    // Time of the last SOL or token withdrawal
+    pub last_withdrawal_ts: u64,
+    // Number of SOL and token withdrawals
+    pub withdrawal_count: u64,
+    // Seconds a queued withdrawal waits before it can be executed, approvers can veto meanwhile
+    pub withdrawal_delay: u64,
+    // Vesting schedule start, vested amount grows linearly from here to vesting_end after the cliff
+    pub vesting_start: u64,
+    // Vesting schedule end, everything is vested from here
+    pub vesting_end: u64,
+    // Total amount released by the vesting schedule
+    pub vesting_total: u64,
+    // Amount withdrawn against the vesting schedule
+    pub vesting_withdrawn: u64,
+    // Seconds without authority heartbeat after which the recipient can recover funds, 0: Disabled
+    pub inactivity_period: u64,
+    // Time of the last authority heartbeat
+    pub last_activity_ts: u64,
+    // Refunds to the authority are timelocked until this time, creation time for no timelock
+    pub refund_time: u64,
+    // Seconds a destination added by add_destination waits before it can receive funds
+    pub destination_delay: u64,
+    // Lamports above which a withdrawal must be queued and wait withdrawal_delay, 0: Disabled
+    pub queue_threshold: u64,
+    // Lamports withdrawn in each hour of the rolling 24h window, indexed by hour % 24
+    pub hourly_withdrawn: [u64; DAILY_LIMIT_BUCKETS],
+    // Share of each share_recipients in basis points, sums up to BPS_DENOMINATOR
+    pub share_bps: [u16; MAX_SHARES],
     // Mode allows fields to be updatable
     pub update_actor_mode: u8,
     // 1: Allow, 0: Deny
@@ -35,49 +104,531 @@ pub struct ConfigAccount {
     pub config_bump: u8,
     // Escrow vault bump
     pub escrow_bump: u8,
+    // Number of approvers in approvers
+    pub approver_count: u8,
+    // Min number of approvers must sign a withdraw fund action
+    pub approver_threshold: u8,
+    // 1: Vesting schedule limits withdrawals of vesting_mint, 0: Disabled
+    pub vesting_mode: u8,
+    // 1: Every transfer but recovery is blocked until both actors unpause, 0: Active
+    pub paused: u8,
+    // Number of shares in share_recipients, 0: Distribution disabled
+    pub share_count: u8,
+    // Number of mints in allowed_mints, 0: Any mint allowed
+    pub allowed_mint_count: u8,
     // padding for alignment
-    pub padding_0: [u8; 12],
+    pub padding_0: [u8; 4],
 }
 
-const_assert_eq!(ConfigAccount::INIT_SPACE, 160);
+const_assert_eq!(ConfigAccount::INIT_SPACE, 1064);
 
 impl ConfigAccount {
     pub fn init(
         &mut self,
         authority: Pubkey,
-        approver: Pubkey,
+        approvers: &[Pubkey],
+        approver_threshold: u8,
         recipient: Pubkey,
         escrow: Pubkey,
+        escrow_id: u64,
         cliff_time: u64,
         amount_per_day: u64,
+        withdrawal_delay: u64,
         update_actor_mode: u8,
         enable_transfer_full: u8,
         config_bump: u8,
         escrow_bump: u8,
+        vesting: Option<&VestingParams>,
+        inactivity_period: u64,
+        refund_time: u64,
+        destination_delay: u64,
+        queue_threshold: u64,
+        shares: &[Share],
+        allowed_mints: &[Pubkey],
+        current_time: u64,
     ) {
         self.authority = authority;
-        self.approver = approver;
+        self.set_approvers(approvers, approver_threshold);
         self.recipient = recipient;
         self.cliff_time = cliff_time;
         self.amount_per_day = amount_per_day;
+        self.withdrawal_delay = withdrawal_delay;
         self.update_actor_mode = update_actor_mode;
         self.enable_transfer_full = enable_transfer_full;
         self.escrow = escrow;
+        self.escrow_authority = authority;
+        self.escrow_id = escrow_id;
         self.config_bump = config_bump;
         self.escrow_bump = escrow_bump;
+        self.inactivity_period = inactivity_period;
+        self.last_activity_ts = current_time;
+        self.refund_time = refund_time;
+        self.destination_delay = destination_delay;
+        self.queue_threshold = queue_threshold;
+        for (index, share) in shares.iter().enumerate() {
+            self.share_recipients[index] = share.recipient;
+            self.share_bps[index] = share.bps;
+        }
+        self.share_count = shares.len() as u8;
+        self.set_allowed_mints(allowed_mints);
+
+        if let Some(vesting) = vesting {
+            self.vesting_mode = 1;
+            self.vesting_mint = vesting.mint;
+            self.vesting_start = vesting.start;
+            self.vesting_end = vesting.end;
+            self.vesting_total = vesting.total;
+        }
+    }
+
+    pub fn approvers(&self) -> &[Pubkey] {
+        &self.approvers[..self.approver_count as usize]
+    }
+
+    pub fn is_approver(&self, key: &Pubkey) -> bool {
+        self.approvers().contains(key)
+    }
+
+    pub fn set_approvers(&mut self, approvers: &[Pubkey], approver_threshold: u8) {
+        self.approvers = [Pubkey::default(); MAX_APPROVERS];
+        self.approvers[..approvers.len()].copy_from_slice(approvers);
+        self.approver_count = approvers.len() as u8;
+        self.approver_threshold = approver_threshold;
+    }
+
+    pub fn allowed_mints(&self) -> &[Pubkey] {
+        &self.allowed_mints[..self.allowed_mint_count as usize]
     }
+
+    pub fn set_allowed_mints(&mut self, allowed_mints: &[Pubkey]) {
+        self.allowed_mints = [Pubkey::default(); MAX_ALLOWED_MINTS];
+        self.allowed_mints[..allowed_mints.len()].copy_from_slice(allowed_mints);
+        self.allowed_mint_count = allowed_mints.len() as u8;
+    }
+
+    /// Any mint is allowed while the mint allowlist is empty.
+    pub fn validate_mint(&self, mint: &Pubkey) -> Result<()> {
+        require!(
+            self.allowed_mint_count == 0 || self.allowed_mints().contains(mint),
+            LockFundEscrowError::MintNotAllowed
+        );
+
+        Ok(())
+    }
+
+    /// Require at least `approver_threshold` distinct approvers among the `approver`
+    /// signer and the signers passed as remaining accounts.
+    pub fn verify_approvals(
+        &self,
+        approver: &Signer,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<()> {
+        let signers = std::iter::once(approver.key).chain(
+            remaining_accounts
+                .iter()
+                .filter(|account| account.is_signer)
+                .map(|account| account.key),
+        );
+
+        let mut approved: u8 = 0;
+        for signer in signers {
+            if let Some(index) = self.approvers().iter().position(|key| key == signer) {
+                approved |= 1 << index;
+            }
+        }
+
+        require!(
+            approved.count_ones() >= self.approver_threshold as u32,
+            LockFundEscrowError::InsufficientApprovals
+        );
+
+        Ok(())
+    }
+
+    pub fn is_actor(&self, key: &Pubkey) -> bool {
+        self.authority == *key || self.is_approver(key)
+    }
+
+    pub fn validate_not_paused(&self) -> Result<()> {
+        require!(self.paused == 0, LockFundEscrowError::EscrowPaused);
+
+        Ok(())
+    }
+
+    pub fn validate_cliff_time(&self, current_time: u64) -> Result<()> {
+        require!(
+            current_time >= self.cliff_time,
+            LockFundEscrowError::CliffTimeNotReached
+        );
+        msg!("cliff time passed: {}", self.cliff_time);
+
+        Ok(())
+    }
+
+    /// Replace the actor selected by `mode`, returning the previous one.
+    pub fn update_actor(&mut self, mode: UpdateActorMode, new_actor: Pubkey) -> Result<Pubkey> {
+        require!(
+            mode != UpdateActorMode::None,
+            LockFundEscrowError::InvalidActorMode
+        );
+        require!(
+            self.update_actor_mode & mode as u8 != 0,
+            LockFundEscrowError::ActorNotUpdatable
+        );
+
+        let actor = match mode {
+            UpdateActorMode::Recipient => &mut self.recipient,
+            // authority is handed over by `propose_new_authority` and `accept_authority`,
+            // approver set is replaced as a whole by `set_approvers`
+            // and mint allowlist by `set_allowed_mints`
+            UpdateActorMode::Authority
+            | UpdateActorMode::Approver
+            | UpdateActorMode::Mints
+            | UpdateActorMode::None => return err!(LockFundEscrowError::InvalidActorMode),
+        };
+
+        Ok(std::mem::replace(actor, new_actor))
+    }
+
+    /// Propose `new_authority`, Pubkey::default() cancels a pending handover.
+    pub fn propose_authority(&mut self, new_authority: Pubkey) -> Result<()> {
+        require!(
+            self.update_actor_mode & UpdateActorMode::Authority as u8 != 0,
+            LockFundEscrowError::ActorNotUpdatable
+        );
+        require!(
+            new_authority != self.authority && !self.is_approver(&new_authority),
+            LockFundEscrowError::DuplicatePubkey
+        );
+        self.pending_authority = new_authority;
+
+        Ok(())
+    }
+
+    /// Hand over to the pending authority, returning the previous one.
+    /// The escrow stays derived from `escrow_authority`.
+    pub fn accept_authority(&mut self, new_authority: Pubkey) -> Result<Pubkey> {
+        require!(
+            self.pending_authority != Pubkey::default() && self.pending_authority == new_authority,
+            LockFundEscrowError::InvalidPendingAuthority
+        );
+        require!(
+            !self.is_approver(&new_authority),
+            LockFundEscrowError::DuplicatePubkey
+        );
+        self.pending_authority = Pubkey::default();
+
+        Ok(std::mem::replace(&mut self.authority, new_authority))
+    }
+
+    /// Funds go to the config recipient or to an active allowlisted `destination`.
+    pub fn validate_recipient(
+        &self,
+        recipient: &Pubkey,
+        destination: Option<&Destination>,
+        current_time: u64,
+    ) -> Result<()> {
+        if *recipient == self.recipient {
+            return Ok(());
+        }
+
+        match destination {
+            Some(destination) if destination.destination == *recipient => {
+                destination.validate_active(current_time)
+            }
+            _ => err!(LockFundEscrowError::InvalidRecipient),
+        }
+    }
+
+    /// Split `amount` across the share table, rounding down each leg.
+    pub fn share_amounts(&self, amount: u64) -> Result<Vec<(Pubkey, u64)>> {
+        require!(
+            self.share_count > 0,
+            LockFundEscrowError::DistributionDisabled
+        );
+
+        let share_count = self.share_count as usize;
+        Ok(self.share_recipients[..share_count]
+            .iter()
+            .zip(&self.share_bps[..share_count])
+            .map(|(recipient, bps)| {
+                let leg = amount as u128 * *bps as u128 / BPS_DENOMINATOR as u128;
+                (*recipient, leg as u64)
+            })
+            .collect())
+    }
+
+    pub fn validate_refund_time(&self, current_time: u64) -> Result<()> {
+        require!(
+            current_time >= self.refund_time,
+            LockFundEscrowError::RefundTimelocked
+        );
+
+        Ok(())
+    }
+
+    /// Recipient can recover funds once the authority missed heartbeats for `inactivity_period`.
+    pub fn validate_recoverable(&self, current_time: u64) -> Result<()> {
+        require!(
+            self.inactivity_period > 0,
+            LockFundEscrowError::RecoveryDisabled
+        );
+        let recoverable_at = self
+            .last_activity_ts
+            .checked_add(self.inactivity_period)
+            .ok_or(LockFundEscrowError::MathOverflow)?;
+        require!(
+            current_time >= recoverable_at,
+            LockFundEscrowError::AuthorityStillActive
+        );
+
+        Ok(())
+    }
+
+    /// Amount released by the vesting schedule at `current_time`, nothing vests before the cliff.
+    pub fn vested_amount(&self, current_time: u64) -> u64 {
+        if current_time < self.cliff_time {
+            return 0;
+        }
+        if current_time >= self.vesting_end {
+            return self.vesting_total;
+        }
+
+        let elapsed = current_time.saturating_sub(self.vesting_start) as u128;
+        let duration = (self.vesting_end - self.vesting_start) as u128;
+        (self.vesting_total as u128 * elapsed / duration) as u64
+    }
+
+    /// Vested amount of `mint` not withdrawn yet, None when the schedule does not apply to `mint`.
+    pub fn available_vested_amount(&self, current_time: u64, mint: &Pubkey) -> Option<u64> {
+        if self.vesting_mode != 1 || self.vesting_mint != *mint {
+            return None;
+        }
+
+        Some(
+            self.vested_amount(current_time)
+                .saturating_sub(self.vesting_withdrawn),
+        )
+    }
+
+    /// Track `amount` of `mint` against the vesting schedule.
+    /// Returns the vested amount when the schedule applies to `mint`.
+    pub fn consume_vesting(
+        &mut self,
+        current_time: u64,
+        mint: &Pubkey,
+        amount: u64,
+    ) -> Result<Option<u64>> {
+        if self.vesting_mode != 1 || self.vesting_mint != *mint {
+            return Ok(None);
+        }
+
+        let vested_amount = self.vested_amount(current_time);
+        let vesting_withdrawn = self
+            .vesting_withdrawn
+            .checked_add(amount)
+            .ok_or(LockFundEscrowError::MathOverflow)?;
+        require!(
+            vesting_withdrawn <= vested_amount,
+            LockFundEscrowError::VestingLimitExceeded
+        );
+        self.vesting_withdrawn = vesting_withdrawn;
+
+        Ok(Some(vested_amount))
+    }
+
+    /// Refunds leave the vested amount of `mint` not withdrawn yet in the escrow,
+    /// it belongs to the recipient.
+    pub fn validate_refund_amount(
+        &self,
+        current_time: u64,
+        mint: &Pubkey,
+        balance: u64,
+        amount: u64,
+    ) -> Result<()> {
+        if let Some(available) = self.available_vested_amount(current_time, mint) {
+            require!(
+                amount <= balance.saturating_sub(available),
+                LockFundEscrowError::RefundExceedsUnvested
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Update lifetime accounting after a withdrawal, `lamports` is 0 for token withdrawals.
+    pub fn record_withdrawal(&mut self, current_time: u64, lamports: u64) -> Result<()> {
+        self.total_withdrawn_lamports = self
+            .total_withdrawn_lamports
+            .checked_add(lamports)
+            .ok_or(LockFundEscrowError::MathOverflow)?;
+        self.withdrawal_count = self
+            .withdrawal_count
+            .checked_add(1)
+            .ok_or(LockFundEscrowError::MathOverflow)?;
+        self.last_withdrawal_ts = current_time;
+
+        Ok(())
+    }
+
+    pub fn validate_transfer_full(&self) -> Result<()> {
+        require!(
+            self.enable_transfer_full == 1,
+            LockFundEscrowError::TransferFullDisabled
+        );
+
+        Ok(())
+    }
+
+    /// Withdrawals above `queue_threshold` must go through `queue_withdrawal`.
+    pub fn validate_queue_threshold(&self, lamports: u64) -> Result<()> {
+        require!(
+            self.queue_threshold == 0 || lamports <= self.queue_threshold,
+            LockFundEscrowError::WithdrawalMustBeQueued
+        );
+
+        Ok(())
+    }
+
+    pub fn consume_daily_limit(&mut self, current_time: u64, lamports: u64) -> Result<()> {
+        consume_daily_limit(
+            &mut self.hourly_withdrawn,
+            &mut self.last_withdrawal_hour,
+            self.amount_per_day,
+            current_time,
+            lamports,
+        )
+    }
+}
+
+/// Validate an approver set before storing it in `ConfigAccount`.
+pub fn validate_approvers(
+    authority: &Pubkey,
+    approvers: &[Pubkey],
+    approver_threshold: u8,
+) -> Result<()> {
+    require!(
+        !approvers.is_empty() && approvers.len() <= MAX_APPROVERS,
+        LockFundEscrowError::InvalidApproverSet
+    );
+    require!(
+        approver_threshold > 0 && approver_threshold as usize <= approvers.len(),
+        LockFundEscrowError::InvalidApproverThreshold
+    );
+    for (index, approver) in approvers.iter().enumerate() {
+        require_keys_neq!(*authority, *approver, LockFundEscrowError::DuplicatePubkey);
+        require!(
+            !approvers[..index].contains(approver),
+            LockFundEscrowError::DuplicatePubkey
+        );
+    }
+
+    Ok(())
+}
+
+/// Validate a mint allowlist before storing it in `ConfigAccount`.
+pub fn validate_allowed_mints(allowed_mints: &[Pubkey]) -> Result<()> {
+    require!(
+        allowed_mints.len() <= MAX_ALLOWED_MINTS,
+        LockFundEscrowError::InvalidMintAllowlist
+    );
+    for (index, mint) in allowed_mints.iter().enumerate() {
+        require!(
+            !allowed_mints[..index].contains(mint),
+            LockFundEscrowError::DuplicatePubkey
+        );
+    }
+
+    Ok(())
+}
+
+/// Validate a share table before storing it in `ConfigAccount`, an empty table disables distribution.
+pub fn validate_shares(shares: &[Share]) -> Result<()> {
+    if shares.is_empty() {
+        return Ok(());
+    }
+
+    require!(
+        shares.len() <= MAX_SHARES,
+        LockFundEscrowError::InvalidShareTable
+    );
+    let mut total_bps: u64 = 0;
+    for (index, share) in shares.iter().enumerate() {
+        require!(share.bps > 0, LockFundEscrowError::InvalidShareTable);
+        require!(
+            !shares[..index]
+                .iter()
+                .any(|other| other.recipient == share.recipient),
+            LockFundEscrowError::DuplicatePubkey
+        );
+        total_bps += share.bps as u64;
+    }
+    require!(
+        total_bps == BPS_DENOMINATOR,
+        LockFundEscrowError::InvalidShareTable
+    );
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Share {
+    pub recipient: Pubkey,
+    // Share in basis points
+    pub bps: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VestingParams {
+    // Token mint released by the schedule, Pubkey::default() for SOL
+    pub mint: Pubkey,
+    pub start: u64,
+    pub end: u64,
+    pub total: u64,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct CreateConfigParams {
+    pub escrow_id: u64,
+    pub approvers: Vec<Pubkey>,
+    pub approver_threshold: u8,
     pub cliff_time_duration: u64,
     pub amount_per_day: u64,
+    pub withdrawal_delay: u64,
     pub update_actor_mode: u8,
     pub enable_transfer_full: u8,
+    pub vesting: Option<VestingParams>,
+    pub inactivity_period: u64,
+    pub refund_time_duration: u64,
+    pub destination_delay: u64,
+    pub queue_threshold: u64,
+    pub shares: Vec<Share>,
+    pub allowed_mints: Vec<Pubkey>,
 }
 
 impl CreateConfigParams {
     pub fn validate_params(&self) -> Result<()> {
+        require!(
+            self.amount_per_day > 0,
+            LockFundEscrowError::InvalidAmountPerDay
+        );
+        require!(
+            self.update_actor_mode & !UpdateActorMode::MASK == 0,
+            LockFundEscrowError::InvalidActorMode
+        );
+        require!(
+            self.enable_transfer_full <= 1,
+            LockFundEscrowError::InvalidTransferFullFlag
+        );
+        require!(
+            self.cliff_time_duration <= MAX_CLIFF_TIME_DURATION,
+            LockFundEscrowError::CliffTimeTooLong
+        );
+        require!(
+            self.refund_time_duration <= MAX_REFUND_TIME_DURATION,
+            LockFundEscrowError::RefundTimeTooLong
+        );
+
         Ok(())
     }
 
@@ -85,11 +636,12 @@ impl CreateConfigParams {
         &self,
         config_account: &AccountLoader<ConfigAccount>,
         authority: Pubkey,
-        approver: Pubkey,
         recipient: Pubkey,
         escrow_vault: Pubkey,
+        escrow_id: u64,
         cliff_time_duration: u64,
         amount_per_day: u64,
+        withdrawal_delay: u64,
         update_actor_mode: u8,
         enable_transfer_full: u8,
         escrow_bump: u8,
@@ -97,23 +649,181 @@ impl CreateConfigParams {
     ) -> Result<()> {
         self.validate_params()?;
 
-        require_keys_neq!(authority, approver, LockFundEscrowError::DuplicatePubkey);
+        require_keys_neq!(
+            recipient,
+            escrow_vault,
+            LockFundEscrowError::InvalidRecipient
+        );
+        validate_approvers(&authority, &self.approvers, self.approver_threshold)?;
+        validate_shares(&self.shares)?;
+        validate_allowed_mints(&self.allowed_mints)?;
 
         let mut config_account = config_account.load_init()?;
-        let cliff_time = Clock::get()?.unix_timestamp as u64 + cliff_time_duration;
+        let current_time = Clock::get()?.unix_timestamp as u64;
+        let cliff_time = current_time
+            .checked_add(cliff_time_duration)
+            .ok_or(LockFundEscrowError::MathOverflow)?;
+        let refund_time = current_time
+            .checked_add(self.refund_time_duration)
+            .ok_or(LockFundEscrowError::MathOverflow)?;
+        if let Some(vesting) = &self.vesting {
+            require!(
+                vesting.start <= cliff_time
+                    && cliff_time <= vesting.end
+                    && vesting.start < vesting.end,
+                LockFundEscrowError::InvalidVestingSchedule
+            );
+        }
         config_account.init(
             authority,
-            approver,
+            &self.approvers,
+            self.approver_threshold,
             recipient,
             escrow_vault,
+            escrow_id,
             cliff_time,
             amount_per_day,
+            withdrawal_delay,
             update_actor_mode,
             enable_transfer_full,
             escrow_bump,
             escrow_vault_bump,
+            self.vesting.as_ref(),
+            self.inactivity_period,
+            refund_time,
+            self.destination_delay,
+            self.queue_threshold,
+            &self.shares,
+            &self.allowed_mints,
+            current_time,
         );
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params() -> CreateConfigParams {
+        CreateConfigParams {
+            escrow_id: 0,
+            approvers: vec![Pubkey::new_unique()],
+            approver_threshold: 1,
+            cliff_time_duration: 0,
+            amount_per_day: 1,
+            withdrawal_delay: 0,
+            update_actor_mode: 0,
+            enable_transfer_full: 0,
+            vesting: None,
+            inactivity_period: 0,
+            refund_time_duration: 1,
+            destination_delay: 0,
+            queue_threshold: 0,
+            shares: vec![],
+            allowed_mints: vec![],
+        }
+    }
+
+    fn assert_error(result: Result<()>, expected: LockFundEscrowError) {
+        match result.unwrap_err() {
+            Error::AnchorError(error) => {
+                assert_eq!(error.error_code_number, u32::from(expected))
+            }
+            error => panic!("unexpected error: {error}"),
+        }
+    }
+
+    #[test]
+    fn test_validate_params_amount_per_day() {
+        params().validate_params().unwrap();
+
+        let params = CreateConfigParams {
+            amount_per_day: 0,
+            ..params()
+        };
+        assert_error(
+            params.validate_params(),
+            LockFundEscrowError::InvalidAmountPerDay,
+        );
+    }
+
+    #[test]
+    fn test_validate_params_update_actor_mode() {
+        let all_modes = CreateConfigParams {
+            update_actor_mode: UpdateActorMode::MASK,
+            ..params()
+        };
+        all_modes.validate_params().unwrap();
+
+        let undefined_mode = CreateConfigParams {
+            update_actor_mode: UpdateActorMode::MASK + 1,
+            ..params()
+        };
+        assert_error(
+            undefined_mode.validate_params(),
+            LockFundEscrowError::InvalidActorMode,
+        );
+    }
+
+    #[test]
+    fn test_validate_params_enable_transfer_full() {
+        let enabled = CreateConfigParams {
+            enable_transfer_full: 1,
+            ..params()
+        };
+        enabled.validate_params().unwrap();
+
+        let invalid_flag = CreateConfigParams {
+            enable_transfer_full: 2,
+            ..params()
+        };
+        assert_error(
+            invalid_flag.validate_params(),
+            LockFundEscrowError::InvalidTransferFullFlag,
+        );
+    }
+
+    #[test]
+    fn test_validate_params_cliff_time_duration() {
+        let max_cliff = CreateConfigParams {
+            cliff_time_duration: MAX_CLIFF_TIME_DURATION,
+            ..params()
+        };
+        max_cliff.validate_params().unwrap();
+
+        let cliff_too_long = CreateConfigParams {
+            cliff_time_duration: MAX_CLIFF_TIME_DURATION + 1,
+            ..params()
+        };
+        assert_error(
+            cliff_too_long.validate_params(),
+            LockFundEscrowError::CliffTimeTooLong,
+        );
+    }
+
+    #[test]
+    fn test_validate_params_refund_time_duration() {
+        let max_refund = CreateConfigParams {
+            refund_time_duration: MAX_REFUND_TIME_DURATION,
+            ..params()
+        };
+        max_refund.validate_params().unwrap();
+
+        let no_refund_timelock = CreateConfigParams {
+            refund_time_duration: 0,
+            ..params()
+        };
+        no_refund_timelock.validate_params().unwrap();
+
+        let refund_too_long = CreateConfigParams {
+            refund_time_duration: MAX_REFUND_TIME_DURATION + 1,
+            ..params()
+        };
+        assert_error(
+            refund_too_long.validate_params(),
+            LockFundEscrowError::RefundTimeTooLong,
+        );
+    }
+}
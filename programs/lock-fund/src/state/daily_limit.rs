@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+
+use crate::{LockFundEscrowError, DAILY_LIMIT_BUCKETS, SECONDS_PER_HOUR};
+
+/// Track `amount` against a rolling 24h withdrawal window. Withdrawals are summed
+/// in hourly buckets indexed by hour % 24, buckets of hours that left the window
+/// are cleared before the check.
+pub fn consume_daily_limit(
+    hourly_withdrawn: &mut [u64; DAILY_LIMIT_BUCKETS],
+    last_withdrawal_hour: &mut u64,
+    amount_per_day: u64,
+    current_time: u64,
+    amount: u64,
+) -> Result<()> {
+    let buckets = DAILY_LIMIT_BUCKETS as u64;
+    let current_hour = (current_time / SECONDS_PER_HOUR).max(*last_withdrawal_hour);
+    if current_hour - *last_withdrawal_hour >= buckets {
+        *hourly_withdrawn = [0; DAILY_LIMIT_BUCKETS];
+    } else {
+        for hour in *last_withdrawal_hour + 1..=current_hour {
+            hourly_withdrawn[(hour % buckets) as usize] = 0;
+        }
+    }
+
+    let withdrawn_amount = hourly_withdrawn
+        .iter()
+        .try_fold(amount, |total, withdrawn| total.checked_add(*withdrawn))
+        .ok_or(LockFundEscrowError::MathOverflow)?;
+    require!(
+        withdrawn_amount <= amount_per_day,
+        LockFundEscrowError::DailyLimitExceeded
+    );
+    hourly_withdrawn[(current_hour % buckets) as usize] += amount;
+    *last_withdrawal_hour = current_hour;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SECONDS_PER_DAY;
+
+    const AMOUNT_PER_DAY: u64 = 1_000;
+
+    struct Window {
+        hourly_withdrawn: [u64; DAILY_LIMIT_BUCKETS],
+        last_withdrawal_hour: u64,
+    }
+
+    impl Window {
+        fn new() -> Self {
+            Window {
+                hourly_withdrawn: [0; DAILY_LIMIT_BUCKETS],
+                last_withdrawal_hour: 0,
+            }
+        }
+
+        fn consume(&mut self, current_time: u64, amount: u64) -> Result<()> {
+            consume_daily_limit(
+                &mut self.hourly_withdrawn,
+                &mut self.last_withdrawal_hour,
+                AMOUNT_PER_DAY,
+                current_time,
+                amount,
+            )
+        }
+    }
+
+    fn assert_daily_limit_exceeded(result: Result<()>) {
+        match result.unwrap_err() {
+            Error::AnchorError(error) => assert_eq!(
+                error.error_code_number,
+                u32::from(LockFundEscrowError::DailyLimitExceeded)
+            ),
+            error => panic!("unexpected error: {error}"),
+        }
+    }
+
+    #[test]
+    fn test_rejects_amount_over_limit() {
+        let mut window = Window::new();
+        let start = 1_700_000_000;
+
+        window.consume(start, AMOUNT_PER_DAY).unwrap();
+        assert_daily_limit_exceeded(window.consume(start + 60, 1));
+    }
+
+    #[test]
+    fn test_window_rolls_across_day_boundary() {
+        let mut window = Window::new();
+        let start = 1_700_000_000 - 1_700_000_000 % SECONDS_PER_HOUR;
+
+        window.consume(start, 1).unwrap();
+        window
+            .consume(start + SECONDS_PER_DAY - 1, AMOUNT_PER_DAY - 1)
+            .unwrap();
+        // a window opened by the first withdrawal would reset here
+        assert_daily_limit_exceeded(window.consume(start + SECONDS_PER_DAY, AMOUNT_PER_DAY - 1));
+
+        // only the first withdrawal left the window
+        window.consume(start + SECONDS_PER_DAY, 1).unwrap();
+        assert_daily_limit_exceeded(
+            window.consume(start + 2 * SECONDS_PER_DAY - SECONDS_PER_HOUR - 1, 1),
+        );
+
+        // the large withdrawal leaves the window 24h after its hour
+        window
+            .consume(start + 2 * SECONDS_PER_DAY - 1, AMOUNT_PER_DAY - 1)
+            .unwrap();
+    }
+}
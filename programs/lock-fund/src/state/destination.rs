@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::LockFundEscrowError;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Destination {
+    // Escrow config this destination belongs to
+    pub config_account: Pubkey,
+    // Allowlisted wallet, receives funds like the config recipient
+    pub destination: Pubkey,
+    // Time from which transfers to destination are allowed
+    pub activated_at: u64,
+    // Destination bump
+    pub bump: u8,
+}
+
+impl Destination {
+    pub fn validate_active(&self, current_time: u64) -> Result<()> {
+        require!(
+            current_time >= self.activated_at,
+            LockFundEscrowError::DestinationNotActive
+        );
+
+        Ok(())
+    }
+}
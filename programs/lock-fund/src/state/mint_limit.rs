@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::{consume_daily_limit, LockFundEscrowError, DAILY_LIMIT_BUCKETS};
+
+/// Per-mint withdrawal limit and accounting. Token withdrawals of a mint require
+/// its `MintLimit`, created by `set_mint_limit` or by the first `deposit_token`
+/// of the mint with a zero `amount_per_day` until `set_mint_limit` is called.
+#[account]
+#[derive(InitSpace)]
+pub struct MintLimit {
+    // Escrow config this limit belongs to
+    pub config_account: Pubkey,
+    // Token mint the limit applies to
+    pub mint: Pubkey,
+    // Max amount of this mint (in base units) that can be withdrawn per day
+    pub amount_per_day: u64,
+    // Hour (unix time / 3600) of the last withdrawal counted against amount_per_day
+    pub last_withdrawal_hour: u64,
+    // Amount withdrawn in each hour of the rolling 24h window, indexed by hour % 24
+    pub hourly_withdrawn: [u64; DAILY_LIMIT_BUCKETS],
+    // Amount of this mint deposited through deposit_token
+    pub total_deposited: u64,
+    // Amount of this mint ever withdrawn from the escrow
+    pub total_withdrawn: u64,
+    // Mint limit bump
+    pub bump: u8,
+    // Amount above which a withdrawal of this mint must be queued, 0: Disabled
+    pub queue_threshold: u64,
+    // Reserved for future fields, keeps the account size stable
+    pub padding: [u8; 56],
+}
+
+impl MintLimit {
+    /// Withdrawals above `queue_threshold` must go through `queue_withdrawal`.
+    pub fn validate_queue_threshold(&self, amount: u64) -> Result<()> {
+        require!(
+            self.queue_threshold == 0 || amount <= self.queue_threshold,
+            LockFundEscrowError::WithdrawalMustBeQueued
+        );
+
+        Ok(())
+    }
+
+    pub fn consume_daily_limit(&mut self, current_time: u64, amount: u64) -> Result<()> {
+        consume_daily_limit(
+            &mut self.hourly_withdrawn,
+            &mut self.last_withdrawal_hour,
+            self.amount_per_day,
+            current_time,
+            amount,
+        )
+    }
+
+    pub fn record_withdrawal(&mut self, amount: u64) -> Result<()> {
+        self.total_withdrawn = self
+            .total_withdrawn
+            .checked_add(amount)
+            .ok_or(LockFundEscrowError::MathOverflow)?;
+
+        Ok(())
+    }
+}
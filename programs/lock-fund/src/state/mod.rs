@@ -1,3 +1,11 @@
 pub mod config_account;
+pub mod daily_limit;
+pub mod destination;
+pub mod mint_limit;
+pub mod withdrawal_proposal;
 
-pub use config_account::*;
\ No newline at end of file
+pub use config_account::*;
+pub use daily_limit::*;
+pub use destination::*;
+pub use mint_limit::*;
+pub use withdrawal_proposal::*;
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+
+use crate::{ConfigAccount, LockFundEscrowError, MAX_APPROVERS};
+
+#[account]
+#[derive(InitSpace)]
+pub struct WithdrawalProposal {
+    // Escrow config this proposal withdraws from
+    pub config_account: Pubkey,
+    // Sequence number taken from ConfigAccount::proposal_count
+    pub proposal_id: u64,
+    // Token mint to withdraw, Pubkey::default() for SOL
+    pub mint: Pubkey,
+    // Amount to withdraw in lamports or token base units
+    pub amount: u64,
+    // Proposal can not be executed before this time
+    pub executable_at: u64,
+    // Proposal can not be approved or executed after this time
+    pub expiry: u64,
+    // Approvers signed this proposal, only the first approval_count are set
+    pub approvals: [Pubkey; MAX_APPROVERS],
+    // Number of approvers in approvals
+    pub approval_count: u8,
+    // 1: queued by authority and approvers, waits withdrawal_delay before execution
+    pub queued: u8,
+    // Proposal bump
+    pub bump: u8,
+}
+
+impl WithdrawalProposal {
+    pub fn validate_not_expired(&self, current_time: u64) -> Result<()> {
+        require!(
+            current_time < self.expiry,
+            LockFundEscrowError::ProposalExpired
+        );
+
+        Ok(())
+    }
+
+    pub fn validate_executable(&self, current_time: u64) -> Result<()> {
+        require!(
+            current_time >= self.executable_at,
+            LockFundEscrowError::WithdrawalTimelocked
+        );
+
+        self.validate_not_expired(current_time)
+    }
+
+    pub fn has_approved(&self, approver: &Pubkey) -> bool {
+        self.approvals[..self.approval_count as usize].contains(approver)
+    }
+
+    /// Record `approver`, dropping approvals of approvers removed from the config since.
+    pub fn approve(&mut self, config_account: &ConfigAccount, approver: Pubkey) -> Result<()> {
+        let mut approvals = [Pubkey::default(); MAX_APPROVERS];
+        let mut approval_count = 0;
+        for key in &self.approvals[..self.approval_count as usize] {
+            require_keys_neq!(*key, approver, LockFundEscrowError::AlreadyApproved);
+            if config_account.is_approver(key) {
+                approvals[approval_count] = *key;
+                approval_count += 1;
+            }
+        }
+        approvals[approval_count] = approver;
+
+        self.approvals = approvals;
+        self.approval_count = approval_count as u8 + 1;
+
+        Ok(())
+    }
+
+    /// Only approvals of approvers still in the config count towards the threshold.
+    pub fn verify_approvals(&self, config_account: &ConfigAccount) -> Result<()> {
+        let approved = self.approvals[..self.approval_count as usize]
+            .iter()
+            .filter(|approver| config_account.is_approver(approver))
+            .count();
+        require!(
+            approved >= config_account.approver_threshold as usize,
+            LockFundEscrowError::InsufficientApprovals
+        );
+
+        Ok(())
+    }
+}
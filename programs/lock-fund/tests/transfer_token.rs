@@ -0,0 +1,824 @@
+use std::sync::Mutex;
+
+use anchor_lang::{
+    event::EVENT_IX_TAG_LE, prelude::*, solana_program::entrypoint::ProgramResult, Discriminator,
+    InstructionData, ToAccountMetas,
+};
+use anchor_spl::{
+    associated_token::{
+        get_associated_token_address_with_program_id, spl_associated_token_account,
+    },
+    token::spl_token,
+    token_2022::spl_token_2022::{
+        self,
+        extension::{transfer_fee, transfer_hook, ExtensionType, StateWithExtensions},
+    },
+};
+use lock_fund::{LockFundEscrowError, TransferEvent, CONFIG_SEED, ESCROW_SEED, MINT_LIMIT_SEED};
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    instruction::{Instruction, InstructionError},
+    native_token::LAMPORTS_PER_SOL,
+    program_pack::Pack,
+    signature::Keypair,
+    signer::Signer,
+    system_instruction,
+    transaction::{Transaction, TransactionError},
+};
+use spl_tlv_account_resolution::{account::ExtraAccountMeta, state::ExtraAccountMetaList};
+use spl_transfer_hook_interface::{
+    get_extra_account_metas_address, instruction::ExecuteInstruction,
+};
+
+const ESCROW_BALANCE: u64 = 1_000_000;
+
+// 1% Token-2022 transfer fee
+const TRANSFER_FEE_BPS: u16 = 100;
+
+// Program id of the test transfer hook
+const TRANSFER_HOOK_ID: Pubkey = Pubkey::new_from_array([7; 32]);
+
+// emit_cpi! events are self invocations of the program, BanksClient does not return
+// inner instructions so process_instruction records them
+static EVENTS: Mutex<Vec<Vec<u8>>> = Mutex::new(Vec::new());
+
+// anchor entry requires accounts to share the 'info lifetime
+fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    if data.starts_with(&EVENT_IX_TAG_LE) {
+        EVENTS.lock().unwrap().push(data.to_vec());
+    }
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    lock_fund::entry(program_id, accounts, data)
+}
+
+// Test transfer hook, counts the transfers of its mint in the counter account
+// resolved from the extra account metas after source, mint, destination, authority
+// and the validation account
+fn process_transfer_hook(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _data: &[u8],
+) -> ProgramResult {
+    let mut counter = accounts[5].try_borrow_mut_data()?;
+    let count = u64::from_le_bytes(counter[..8].try_into().unwrap());
+    counter[..8].copy_from_slice(&(count + 1).to_le_bytes());
+
+    Ok(())
+}
+
+fn program_test() -> ProgramTest {
+    let mut program_test =
+        ProgramTest::new("lock_fund", lock_fund::ID, processor!(process_instruction));
+    program_test.add_program(
+        "transfer_hook",
+        TRANSFER_HOOK_ID,
+        processor!(process_transfer_hook),
+    );
+
+    program_test
+}
+
+/// Transfer events emitted for `config_account`, event data follows the event tag
+/// and the event discriminator.
+fn transfer_events(config_account: &Pubkey) -> Vec<TransferEvent> {
+    EVENTS
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|data| data[8..16] == TransferEvent::DISCRIMINATOR)
+        .map(|data| TransferEvent::try_from_slice(&data[16..]).unwrap())
+        .filter(|event| event.config_account == *config_account)
+        .collect()
+}
+
+struct Escrow {
+    authority: Keypair,
+    approver: Keypair,
+    recipient: Pubkey,
+    escrow: Pubkey,
+    config_account: Pubkey,
+}
+
+impl Escrow {
+    fn escrow_token(&self, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+        get_associated_token_address_with_program_id(&self.escrow, mint, token_program)
+    }
+
+    fn mint_limit(&self, mint: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[MINT_LIMIT_SEED, self.config_account.as_ref(), mint.as_ref()],
+            &lock_fund::ID,
+        )
+        .0
+    }
+}
+
+async fn process(
+    context: &mut ProgramTestContext,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> std::result::Result<(), BanksClientError> {
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+    let transaction = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(transaction).await
+}
+
+fn assert_error(result: std::result::Result<(), BanksClientError>, error_code: u32) {
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(error_code))
+    );
+}
+
+async fn create_mint(context: &mut ProgramTestContext) -> Pubkey {
+    let mint = Keypair::new();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let instructions = [
+        system_instruction::create_account(
+            &context.payer.pubkey(),
+            &mint.pubkey(),
+            rent.minimum_balance(spl_token::state::Mint::LEN),
+            spl_token::state::Mint::LEN as u64,
+            &spl_token::id(),
+        ),
+        spl_token::instruction::initialize_mint2(
+            &spl_token::id(),
+            &mint.pubkey(),
+            &context.payer.pubkey(),
+            None,
+            6,
+        )
+        .unwrap(),
+    ];
+    process(context, &instructions, &[&mint]).await.unwrap();
+
+    mint.pubkey()
+}
+
+async fn create_transfer_fee_mint(context: &mut ProgramTestContext) -> Pubkey {
+    let mint = Keypair::new();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+        ExtensionType::TransferFeeConfig,
+    ])
+    .unwrap();
+    let instructions = [
+        system_instruction::create_account(
+            &context.payer.pubkey(),
+            &mint.pubkey(),
+            rent.minimum_balance(space),
+            space as u64,
+            &spl_token_2022::id(),
+        ),
+        transfer_fee::instruction::initialize_transfer_fee_config(
+            &spl_token_2022::id(),
+            &mint.pubkey(),
+            None,
+            None,
+            TRANSFER_FEE_BPS,
+            u64::MAX,
+        )
+        .unwrap(),
+        spl_token_2022::instruction::initialize_mint2(
+            &spl_token_2022::id(),
+            &mint.pubkey(),
+            &context.payer.pubkey(),
+            None,
+            6,
+        )
+        .unwrap(),
+    ];
+    process(context, &instructions, &[&mint]).await.unwrap();
+
+    mint.pubkey()
+}
+
+/// Token-2022 mint calling the test transfer hook, the validation account of `mint`
+/// lists `counter` as the only extra account.
+async fn create_transfer_hook_mint(
+    context: &mut ProgramTestContext,
+    mint: &Keypair,
+    counter: &Pubkey,
+) {
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let extra_account_metas = [ExtraAccountMeta::new_with_pubkey(counter, false, true).unwrap()];
+    let mut validation_data =
+        vec![0; ExtraAccountMetaList::size_of(extra_account_metas.len()).unwrap()];
+    ExtraAccountMetaList::init::<ExecuteInstruction>(&mut validation_data, &extra_account_metas)
+        .unwrap();
+    context.set_account(
+        &get_extra_account_metas_address(&mint.pubkey(), &TRANSFER_HOOK_ID),
+        &Account {
+            lamports: rent.minimum_balance(validation_data.len()),
+            data: validation_data,
+            owner: TRANSFER_HOOK_ID,
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+    context.set_account(
+        counter,
+        &Account {
+            lamports: rent.minimum_balance(8),
+            data: vec![0; 8],
+            owner: TRANSFER_HOOK_ID,
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+
+    let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+        ExtensionType::TransferHook,
+    ])
+    .unwrap();
+    let instructions = [
+        system_instruction::create_account(
+            &context.payer.pubkey(),
+            &mint.pubkey(),
+            rent.minimum_balance(space),
+            space as u64,
+            &spl_token_2022::id(),
+        ),
+        transfer_hook::instruction::initialize(
+            &spl_token_2022::id(),
+            &mint.pubkey(),
+            None,
+            Some(TRANSFER_HOOK_ID),
+        )
+        .unwrap(),
+        spl_token_2022::instruction::initialize_mint2(
+            &spl_token_2022::id(),
+            &mint.pubkey(),
+            &context.payer.pubkey(),
+            None,
+            6,
+        )
+        .unwrap(),
+    ];
+    process(context, &instructions, &[mint]).await.unwrap();
+}
+
+/// Token program owning `mint`, the token or the Token-2022 program.
+async fn token_program(context: &mut ProgramTestContext, mint: &Pubkey) -> Pubkey {
+    context
+        .banks_client
+        .get_account(*mint)
+        .await
+        .unwrap()
+        .unwrap()
+        .owner
+}
+
+async fn create_token_account(
+    context: &mut ProgramTestContext,
+    owner: &Pubkey,
+    mint: &Pubkey,
+    amount: u64,
+) -> Pubkey {
+    let token_program = token_program(context, mint).await;
+    let token_account = get_associated_token_address_with_program_id(owner, mint, &token_program);
+    let instructions = [
+        spl_associated_token_account::instruction::create_associated_token_account(
+            &context.payer.pubkey(),
+            owner,
+            mint,
+            &token_program,
+        ),
+        spl_token_2022::instruction::mint_to(
+            &token_program,
+            mint,
+            &token_account,
+            &context.payer.pubkey(),
+            &[],
+            amount,
+        )
+        .unwrap(),
+    ];
+    process(context, &instructions, &[]).await.unwrap();
+
+    token_account
+}
+
+async fn token_balance(context: &mut ProgramTestContext, token_account: &Pubkey) -> u64 {
+    let account = context
+        .banks_client
+        .get_account(*token_account)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // Token-2022 accounts carry extensions after the base account
+    StateWithExtensions::<spl_token_2022::state::Account>::unpack(&account.data)
+        .unwrap()
+        .base
+        .amount
+}
+
+async fn create_escrow(
+    context: &mut ProgramTestContext,
+    recipient: Pubkey,
+    mint: &Pubkey,
+) -> Escrow {
+    let authority = Keypair::new();
+    let approver = Keypair::new();
+    let (escrow, _bump) = Pubkey::find_program_address(
+        &[
+            ESCROW_SEED,
+            authority.pubkey().as_ref(),
+            0u64.to_le_bytes().as_ref(),
+        ],
+        &lock_fund::ID,
+    );
+    let (config_account, _bump) =
+        Pubkey::find_program_address(&[CONFIG_SEED, escrow.as_ref()], &lock_fund::ID);
+    let escrow = Escrow {
+        authority,
+        approver,
+        recipient,
+        escrow,
+        config_account,
+    };
+
+    let create_config = Instruction {
+        program_id: lock_fund::ID,
+        accounts: lock_fund::accounts::CreateConfig {
+            authority: escrow.authority.pubkey(),
+            config_account: escrow.config_account,
+            escrow: escrow.escrow,
+            recipient,
+            system_program: solana_sdk::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: lock_fund::instruction::CreateConfig {
+            params: lock_fund::CreateConfigParams {
+                escrow_id: 0,
+                approvers: vec![escrow.approver.pubkey()],
+                approver_threshold: 1,
+                cliff_time_duration: 0,
+                amount_per_day: ESCROW_BALANCE,
+                withdrawal_delay: 0,
+                update_actor_mode: 0,
+                enable_transfer_full: 0,
+                vesting: None,
+                inactivity_period: 0,
+                refund_time_duration: 0,
+                destination_delay: 0,
+                queue_threshold: 0,
+                shares: vec![],
+                allowed_mints: vec![*mint],
+            },
+        }
+        .data(),
+    };
+    let fund_authority = system_instruction::transfer(
+        &context.payer.pubkey(),
+        &escrow.authority.pubkey(),
+        LAMPORTS_PER_SOL,
+    );
+    process(
+        context,
+        &[
+            fund_authority,
+            create_config,
+            set_mint_limit(&escrow, mint, 0),
+        ],
+        &[&escrow.authority, &escrow.approver],
+    )
+    .await
+    .unwrap();
+    create_token_account(context, &escrow.escrow, mint, ESCROW_BALANCE).await;
+
+    escrow
+}
+
+fn set_mint_limit(escrow: &Escrow, mint: &Pubkey, queue_threshold: u64) -> Instruction {
+    Instruction {
+        program_id: lock_fund::ID,
+        accounts: lock_fund::accounts::SetMintLimit {
+            config_account: escrow.config_account,
+            mint_limit: escrow.mint_limit(mint),
+            mint_token: *mint,
+            authority: escrow.authority.pubkey(),
+            approver: escrow.approver.pubkey(),
+            system_program: solana_sdk::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: lock_fund::instruction::SetMintLimit {
+            amount_per_day: ESCROW_BALANCE,
+            queue_threshold,
+        }
+        .data(),
+    }
+}
+
+/// Transfer `mint` of `token_program` out of the escrow accounts of `escrow` using the
+/// config and mint limit of `config`, signed by `authority` and `approver`.
+fn transfer_token(
+    config: &Escrow,
+    escrow: &Escrow,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    authority: &Keypair,
+    approver: &Keypair,
+    amount: u64,
+) -> Instruction {
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[b"__event_authority"], &lock_fund::ID);
+
+    Instruction {
+        program_id: lock_fund::ID,
+        accounts: lock_fund::accounts::TransferToken {
+            config_account: config.config_account,
+            escrow: escrow.escrow,
+            escrow_token: escrow.escrow_token(mint, token_program),
+            recipient_token: get_associated_token_address_with_program_id(
+                &escrow.recipient,
+                mint,
+                token_program,
+            ),
+            recipient: escrow.recipient,
+            destination: None,
+            mint_token: *mint,
+            mint_limit: config.mint_limit(mint),
+            authority: authority.pubkey(),
+            approver: approver.pubkey(),
+            token_program: *token_program,
+            associated_token_program: spl_associated_token_account::id(),
+            system_program: solana_sdk::system_program::ID,
+            event_authority,
+            program: lock_fund::ID,
+        }
+        .to_account_metas(None),
+        data: lock_fund::instruction::TransferToken {
+            amount,
+            approver_count: 0,
+        }
+        .data(),
+    }
+}
+
+/// Recipient of `escrow` claims `mint` of `token_program`, signed by the recipient.
+fn claim_token(escrow: &Escrow, mint: &Pubkey, token_program: &Pubkey, amount: u64) -> Instruction {
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[b"__event_authority"], &lock_fund::ID);
+
+    Instruction {
+        program_id: lock_fund::ID,
+        accounts: lock_fund::accounts::ClaimToken {
+            config_account: escrow.config_account,
+            escrow: escrow.escrow,
+            escrow_token: escrow.escrow_token(mint, token_program),
+            recipient_token: get_associated_token_address_with_program_id(
+                &escrow.recipient,
+                mint,
+                token_program,
+            ),
+            recipient: escrow.recipient,
+            mint_token: *mint,
+            mint_limit: escrow.mint_limit(mint),
+            token_program: *token_program,
+            event_authority,
+            program: lock_fund::ID,
+        }
+        .to_account_metas(None),
+        data: lock_fund::instruction::ClaimToken { amount }.data(),
+    }
+}
+
+async fn setup() -> (ProgramTestContext, Pubkey, Escrow, Escrow) {
+    let mut context = program_test().start_with_context().await;
+
+    let mint = create_mint(&mut context).await;
+    // recipient token account is created by transfer_token
+    let recipient = Pubkey::new_unique();
+    let victim = create_escrow(&mut context, recipient, &mint).await;
+    let attacker = create_escrow(&mut context, recipient, &mint).await;
+
+    (context, mint, victim, attacker)
+}
+
+#[tokio::test]
+async fn test_transfer_token_by_escrow_actors() {
+    let (mut context, mint, victim, _attacker) = setup().await;
+
+    let instruction = transfer_token(
+        &victim,
+        &victim,
+        &mint,
+        &spl_token::id(),
+        &victim.authority,
+        &victim.approver,
+        ESCROW_BALANCE,
+    );
+    process(
+        &mut context,
+        &[instruction],
+        &[&victim.authority, &victim.approver],
+    )
+    .await
+    .unwrap();
+
+    let recipient_token =
+        get_associated_token_address_with_program_id(&victim.recipient, &mint, &spl_token::id());
+    assert_eq!(
+        token_balance(&mut context, &recipient_token).await,
+        ESCROW_BALANCE
+    );
+    assert_eq!(
+        token_balance(&mut context, &victim.escrow_token(&mint, &spl_token::id())).await,
+        0
+    );
+}
+
+#[tokio::test]
+async fn test_transfer_token_rejects_foreign_signers() {
+    let (mut context, mint, victim, attacker) = setup().await;
+
+    let instruction = transfer_token(
+        &victim,
+        &victim,
+        &mint,
+        &spl_token::id(),
+        &attacker.authority,
+        &attacker.approver,
+        ESCROW_BALANCE,
+    );
+    let result = process(
+        &mut context,
+        &[instruction],
+        &[&attacker.authority, &attacker.approver],
+    )
+    .await;
+
+    assert_error(result, ErrorCode::ConstraintHasOne.into());
+}
+
+#[tokio::test]
+async fn test_transfer_token_rejects_foreign_approver() {
+    let (mut context, mint, victim, attacker) = setup().await;
+
+    let instruction = transfer_token(
+        &victim,
+        &victim,
+        &mint,
+        &spl_token::id(),
+        &victim.authority,
+        &attacker.approver,
+        ESCROW_BALANCE,
+    );
+    let result = process(
+        &mut context,
+        &[instruction],
+        &[&victim.authority, &attacker.approver],
+    )
+    .await;
+
+    assert_error(result, LockFundEscrowError::InsufficientApprovals.into());
+}
+
+#[tokio::test]
+async fn test_transfer_token_rejects_foreign_escrow() {
+    let (mut context, mint, victim, attacker) = setup().await;
+
+    // attacker signs for its own config but points escrow accounts at the victim
+    let instruction = transfer_token(
+        &attacker,
+        &victim,
+        &mint,
+        &spl_token::id(),
+        &attacker.authority,
+        &attacker.approver,
+        ESCROW_BALANCE,
+    );
+    let result = process(
+        &mut context,
+        &[instruction],
+        &[&attacker.authority, &attacker.approver],
+    )
+    .await;
+
+    assert_error(result, LockFundEscrowError::InvalidEscrow.into());
+}
+
+#[tokio::test]
+async fn test_transfer_token_rejects_mint_not_allowed() {
+    let (mut context, _mint, victim, _attacker) = setup().await;
+
+    // mint limit and escrow balance exist, only the allowlist rejects the mint
+    let other_mint = create_mint(&mut context).await;
+    process(
+        &mut context,
+        &[set_mint_limit(&victim, &other_mint, 0)],
+        &[&victim.authority, &victim.approver],
+    )
+    .await
+    .unwrap();
+    create_token_account(&mut context, &victim.escrow, &other_mint, ESCROW_BALANCE).await;
+
+    let instruction = transfer_token(
+        &victim,
+        &victim,
+        &other_mint,
+        &spl_token::id(),
+        &victim.authority,
+        &victim.approver,
+        ESCROW_BALANCE,
+    );
+    let result = process(
+        &mut context,
+        &[instruction],
+        &[&victim.authority, &victim.approver],
+    )
+    .await;
+
+    assert_error(result, LockFundEscrowError::MintNotAllowed.into());
+}
+
+#[tokio::test]
+async fn test_transfer_token_rejects_amount_above_queue_threshold() {
+    let (mut context, mint, victim, _attacker) = setup().await;
+    let queue_threshold = ESCROW_BALANCE / 2;
+    process(
+        &mut context,
+        &[set_mint_limit(&victim, &mint, queue_threshold)],
+        &[&victim.authority, &victim.approver],
+    )
+    .await
+    .unwrap();
+
+    let instruction = transfer_token(
+        &victim,
+        &victim,
+        &mint,
+        &spl_token::id(),
+        &victim.authority,
+        &victim.approver,
+        queue_threshold + 1,
+    );
+    let result = process(
+        &mut context,
+        &[instruction],
+        &[&victim.authority, &victim.approver],
+    )
+    .await;
+    assert_error(result, LockFundEscrowError::WithdrawalMustBeQueued.into());
+
+    let instruction = transfer_token(
+        &victim,
+        &victim,
+        &mint,
+        &spl_token::id(),
+        &victim.authority,
+        &victim.approver,
+        queue_threshold,
+    );
+    process(
+        &mut context,
+        &[instruction],
+        &[&victim.authority, &victim.approver],
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_transfer_token_2022_with_transfer_fee() {
+    let (mut context, _mint, _victim, _attacker) = setup().await;
+    let mint = create_transfer_fee_mint(&mut context).await;
+    let escrow = create_escrow(&mut context, Pubkey::new_unique(), &mint).await;
+
+    let instruction = transfer_token(
+        &escrow,
+        &escrow,
+        &mint,
+        &spl_token_2022::id(),
+        &escrow.authority,
+        &escrow.approver,
+        ESCROW_BALANCE,
+    );
+    process(
+        &mut context,
+        &[instruction],
+        &[&escrow.authority, &escrow.approver],
+    )
+    .await
+    .unwrap();
+
+    let fee = ESCROW_BALANCE * TRANSFER_FEE_BPS as u64 / 10_000;
+    let recipient_token = get_associated_token_address_with_program_id(
+        &escrow.recipient,
+        &mint,
+        &spl_token_2022::id(),
+    );
+    assert_eq!(
+        token_balance(&mut context, &recipient_token).await,
+        ESCROW_BALANCE - fee
+    );
+    assert_eq!(
+        token_balance(
+            &mut context,
+            &escrow.escrow_token(&mint, &spl_token_2022::id())
+        )
+        .await,
+        0
+    );
+
+    let events = transfer_events(&escrow.config_account);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].to, recipient_token);
+    assert_eq!(events[0].recipient, Some(escrow.recipient));
+    assert_eq!(events[0].amount, ESCROW_BALANCE);
+    assert_eq!(events[0].net_amount, ESCROW_BALANCE - fee);
+}
+
+#[tokio::test]
+async fn test_claim_token_2022_with_transfer_fee() {
+    let mut context = program_test().start_with_context().await;
+    let mint = create_transfer_fee_mint(&mut context).await;
+    let recipient = Keypair::new();
+    let escrow = create_escrow(&mut context, recipient.pubkey(), &mint).await;
+    let recipient_token = create_token_account(&mut context, &recipient.pubkey(), &mint, 0).await;
+
+    let instruction = claim_token(&escrow, &mint, &spl_token_2022::id(), ESCROW_BALANCE);
+    process(&mut context, &[instruction], &[&recipient])
+        .await
+        .unwrap();
+
+    let fee = ESCROW_BALANCE * TRANSFER_FEE_BPS as u64 / 10_000;
+    assert_eq!(
+        token_balance(&mut context, &recipient_token).await,
+        ESCROW_BALANCE - fee
+    );
+
+    let events = transfer_events(&escrow.config_account);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].amount, ESCROW_BALANCE);
+    assert_eq!(events[0].net_amount, ESCROW_BALANCE - fee);
+}
+
+#[tokio::test]
+async fn test_transfer_token_2022_with_transfer_hook() {
+    let mut context = program_test().start_with_context().await;
+    let mint = Keypair::new();
+    let counter = Pubkey::new_unique();
+    create_transfer_hook_mint(&mut context, &mint, &counter).await;
+    let mint = mint.pubkey();
+    let escrow = create_escrow(&mut context, Pubkey::new_unique(), &mint).await;
+
+    // hook accounts follow the approver_count other approvers, none here
+    let mut instruction = transfer_token(
+        &escrow,
+        &escrow,
+        &mint,
+        &spl_token_2022::id(),
+        &escrow.authority,
+        &escrow.approver,
+        ESCROW_BALANCE,
+    );
+    instruction.accounts.extend([
+        AccountMeta::new_readonly(TRANSFER_HOOK_ID, false),
+        AccountMeta::new_readonly(
+            get_extra_account_metas_address(&mint, &TRANSFER_HOOK_ID),
+            false,
+        ),
+        AccountMeta::new(counter, false),
+    ]);
+    process(
+        &mut context,
+        &[instruction],
+        &[&escrow.authority, &escrow.approver],
+    )
+    .await
+    .unwrap();
+
+    let recipient_token = get_associated_token_address_with_program_id(
+        &escrow.recipient,
+        &mint,
+        &spl_token_2022::id(),
+    );
+    assert_eq!(
+        token_balance(&mut context, &recipient_token).await,
+        ESCROW_BALANCE
+    );
+    let counter = context
+        .banks_client
+        .get_account(counter)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(counter.data, 1u64.to_le_bytes());
+}
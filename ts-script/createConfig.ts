@@ -4,12 +4,21 @@ import { TOKEN_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/utils/token";
 import { LockFund } from "../target/types/lock_fund";
 
 type CreateConfigParams = {
+  escrowId: anchor.BN;
   recipient: anchor.web3.PublicKey;
-  approver: anchor.web3.PublicKey;
+  approvers: anchor.web3.PublicKey[];
+  approverThreshold: number;
   cliffTimeDuration: anchor.BN;
   amountPerDay: anchor.BN;
+  withdrawalDelay: anchor.BN;
   updateActorMode: number;
   enableTransferFull: number;
+  inactivityPeriod: anchor.BN;
+  refundTimeDuration: anchor.BN;
+  destinationDelay: anchor.BN;
+  queueThreshold: anchor.BN;
+  shares: { recipient: anchor.web3.PublicKey; bps: number }[];
+  allowedMints: anchor.web3.PublicKey[];
 };
 const createConfig = async (
   program: anchor.Program<LockFund>,
@@ -17,31 +26,50 @@ const createConfig = async (
   params: CreateConfigParams
 ): Promise<string> => {
   const {
+    escrowId,
     recipient,
-    approver,
+    approvers,
+    approverThreshold,
     cliffTimeDuration,
     amountPerDay,
+    withdrawalDelay,
     updateActorMode,
     enableTransferFull,
+    inactivityPeriod,
+    refundTimeDuration,
+    destinationDelay,
+    queueThreshold,
+    shares,
+    allowedMints,
   } = params;
-  const escrow = getEscrowAccount(program);
-  const configAccount = getConfigAccount(program);
+  const escrow = getEscrowAccount(program, escrowId);
+  const configAccount = getConfigAccount(program, escrowId);
 
   const accounts = {
     authority: authority.publicKey,
     configAccount,
     escrow,
     recipient,
-    approver,
     systemProgram: anchor.web3.SystemProgram.programId,
     tokenProgram: TOKEN_PROGRAM_ID,
   };
   return await program.methods
     .createConfig({
+      escrowId,
+      approvers,
+      approverThreshold,
       cliffTimeDuration,
       amountPerDay,
+      withdrawalDelay,
       updateActorMode,
       enableTransferFull,
+      vesting: null,
+      inactivityPeriod,
+      refundTimeDuration,
+      destinationDelay,
+      queueThreshold,
+      shares,
+      allowedMints,
     })
     .accounts(accounts)
     .signers([authority])
@@ -64,20 +92,35 @@ const createConfig = async (
   );
 
   // Define Params
+  const escrowId = new anchor.BN(0);
   const cliffTimeDuration = new anchor.BN(0); //
   const amountPerDay = new anchor.BN(1000 * 10e6);
+  const withdrawalDelay = new anchor.BN(24 * 60 * 60);
   const updateActorMode = 1;
   const enableTransferFull = 0;
+  const inactivityPeriod = new anchor.BN(0); // recovery disabled
+  const refundTimeDuration = new anchor.BN(30 * 24 * 60 * 60); // refund timelock, 0: no timelock
+  const destinationDelay = new anchor.BN(24 * 60 * 60);
+  const queueThreshold = new anchor.BN(0); // withdrawals above it must be queued, 0: disabled
 
   const program = getProgram(connection, new anchor.Wallet(authority));
 
   const createConfigParams: CreateConfigParams = {
+    escrowId,
     recipient,
-    approver,
+    approvers: [approver],
+    approverThreshold: 1,
     cliffTimeDuration,
     amountPerDay,
+    withdrawalDelay,
     updateActorMode,
     enableTransferFull,
+    inactivityPeriod,
+    refundTimeDuration,
+    destinationDelay,
+    queueThreshold,
+    shares: [], // distribution disabled
+    allowedMints: [], // any mint allowed
   };
 
   const signature = await createConfig(program, authority, createConfigParams);
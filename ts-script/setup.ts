@@ -19,19 +19,26 @@ export function getProgram(
   return new anchor.Program<LockFund>(idl as LockFund, provider);
 }
 
-export function getConfigAccount(program: anchor.Program<LockFund>) {
-  const escrow = getEscrowAccount(program);
+export function getConfigAccount(
+  program: anchor.Program<LockFund>,
+  escrowId: anchor.BN = new anchor.BN(0)
+) {
+  const escrow = getEscrowAccount(program, escrowId);
   return anchor.web3.PublicKey.findProgramAddressSync(
     [getSeed("configSeed", program), escrow.toBuffer()],
     program.programId
   )[0];
 }
 
-export function getEscrowAccount(program: anchor.Program<LockFund>) {
+export function getEscrowAccount(
+  program: anchor.Program<LockFund>,
+  escrowId: anchor.BN = new anchor.BN(0)
+) {
   return anchor.web3.PublicKey.findProgramAddressSync(
     [
       getSeed("escrowSeed", program),
       program.provider.publicKey.toBuffer(),
+      escrowId.toArrayLike(Buffer, "le", 8),
     ],
     program.programId
   )[0];
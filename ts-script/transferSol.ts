@@ -24,6 +24,7 @@ const transferSOL = async (
     configAccount,
     escrow,
     recipient: configAccountData.recipient,
+    destination: null,
     authority: authority.publicKey,
     approver: approver.publicKey,
     systemProgram: anchor.web3.SystemProgram.programId,
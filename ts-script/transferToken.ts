@@ -1,7 +1,6 @@
 import * as anchor from "@coral-xyz/anchor";
 import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
-  createAssociatedTokenAccountInstruction,
   getAssociatedTokenAddressSync,
 } from "@solana/spl-token";
 import { getProgram, getEscrowAccount, getConfigAccount } from "./setup";
@@ -23,54 +22,48 @@ const transferToken = async (
   const configAccountData = await program.account.configAccount.fetch(
     configAccount
   );
+  const tokenInfo = await program.provider.connection.getParsedAccountInfo(
+    mintToken
+  );
+  const tokenProgram = tokenInfo.value.owner;
   const recipientToken = getAssociatedTokenAddressSync(
     mintToken,
-    configAccountData.recipient
+    configAccountData.recipient,
+    false,
+    tokenProgram
   );
 
-  const escrowToken = getAssociatedTokenAddressSync(mintToken, escrow, true);
+  const escrowToken = getAssociatedTokenAddressSync(
+    mintToken,
+    escrow,
+    true,
+    tokenProgram
+  );
 
   console.log({
     escrow: escrow.toString(),
     escrowToken: escrowToken.toString(),
     recipientToken: recipientToken.toString(),
   });
-  const tokenInfo = await program.provider.connection.getParsedAccountInfo(
-    mintToken
-  );
-
-  const preInstruction = [];
-  const recipientAccount = await program.provider.connection.getAccountInfo(
-    recipientToken
-  );
-  if (!recipientAccount) {
-    preInstruction.push(
-      createAssociatedTokenAccountInstruction(
-        authority.publicKey,
-        recipientToken,
-        configAccountData.recipient,
-        mintToken,
-        tokenInfo.value.owner,
-        ASSOCIATED_TOKEN_PROGRAM_ID
-      )
-    );
-  }
+  // recipient token account is created by the program when missing
   const accounts = {
     configAccount,
     escrow,
     escrowToken,
     recipientToken,
     recipient: configAccountData.recipient,
+    destination: null,
     mintToken,
     authority: authority.publicKey,
     approver: approver.publicKey,
-    tokenProgram: tokenInfo.value.owner,
+    tokenProgram,
+    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+    systemProgram: anchor.web3.SystemProgram.programId,
   };
 
   return await program.methods
-    .transferToken(amount)
+    .transferToken(amount, 0) // no other approvers, remaining accounts are hook accounts
     .accounts(accounts)
-    .preInstructions(preInstruction)
     .signers([authority, approver])
     .rpc();
 };